@@ -0,0 +1,83 @@
+use std::{error::Error, fmt::Display};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bs58_fixed::Bs58StrDecodeErr;
+use clap::ValueEnum;
+
+/// Non-base58 representations of byte buffers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// lowercase hex, without `0x` prefix
+    Hex,
+
+    /// standard base64, with padding
+    Base64,
+
+    /// JSON array of numbers e.g. `[1,2,3]`
+    Json,
+}
+
+impl Format {
+    pub fn parse(self, s: &str) -> Result<Vec<u8>, ItemErr> {
+        match self {
+            Self::Hex => hex::decode(s).map_err(ItemErr::Hex),
+            Self::Base64 => STANDARD.decode(s).map_err(ItemErr::Base64),
+            Self::Json => serde_json::from_str(s).map_err(ItemErr::Json),
+        }
+    }
+
+    pub fn format(self, buf: &[u8]) -> String {
+        match self {
+            Self::Hex => hex::encode(buf),
+            Self::Base64 => STANDARD.encode(buf),
+            // serializing a byte slice to a JSON string never fails
+            Self::Json => serde_json::to_string(buf).unwrap(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ItemErr {
+    Hex(hex::FromHexError),
+    Base64(base64::DecodeError),
+    Json(serde_json::Error),
+    Bs58(Bs58StrDecodeErr),
+}
+
+impl Display for ItemErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hex(e) => write!(f, "invalid hex: {e}"),
+            Self::Base64(e) => write!(f, "invalid base64: {e}"),
+            Self::Json(e) => write!(f, "invalid JSON byte array: {e}"),
+            Self::Bs58(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for ItemErr {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let buf = [0u8, 1, 0xfe, 0xff];
+        for (format, s) in [
+            (Format::Hex, "0001feff"),
+            (Format::Base64, "AAH+/w=="),
+            (Format::Json, "[0,1,254,255]"),
+        ] {
+            assert_eq!(format.format(&buf), s);
+            assert_eq!(format.parse(s).unwrap(), buf);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(Format::Hex.parse("0x00"), Err(ItemErr::Hex(_))));
+        assert!(matches!(Format::Base64.parse("!"), Err(ItemErr::Base64(_))));
+        assert!(matches!(Format::Json.parse("[256]"), Err(ItemErr::Json(_))));
+    }
+}
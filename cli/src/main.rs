@@ -0,0 +1,310 @@
+#![doc = include_str!("../README.md")]
+
+mod format;
+
+use std::{
+    io::{self, BufRead, BufWriter, Write},
+    process::ExitCode,
+};
+
+use bs58_fixed::{max_str_len, Bs58LineReader, Bs58Str, Bs58StrDecodeErr, Bs58String};
+use clap::{Parser, Subcommand};
+
+use crate::format::{Format, ItemErr};
+
+macro_rules! predefined_lens {
+    ($($BUF_LEN:expr),*) => {
+        /// All `BUF_LEN`s supported by `--len`
+        const LENS: &[usize] = &[$($BUF_LEN),*];
+
+        /// Runs `cmd` with `BUF_LEN = len`.
+        ///
+        /// `len` must be in [`LENS`], which is checked by clap
+        fn run_for_len(
+            cmd: &Cmd,
+            len: usize,
+            stdin: impl BufRead,
+            out: impl Write,
+            err: impl Write,
+        ) -> io::Result<bool> {
+            match len {
+                $($BUF_LEN => run::<$BUF_LEN, { max_str_len($BUF_LEN) }>(cmd, stdin, out, err),)*
+                _ => unreachable!("unsupported len {len}"),
+            }
+        }
+    };
+}
+
+bs58_fixed::__for_each_predefined_len!(predefined_lens);
+
+fn parse_len(s: &str) -> Result<usize, String> {
+    let len: usize = s.parse().map_err(|e| format!("{e}"))?;
+    if LENS.contains(&len) {
+        Ok(len)
+    } else {
+        Err(format!("unsupported len, must be one of {LENS:?}"))
+    }
+}
+
+/// Converts fixed-size buffers between base58 and other formats,
+/// with strict size checking
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Args {
+    #[command(subcommand)]
+    cmd: Cmd,
+
+    /// Byte length of the buffers
+    #[arg(short, long, global = true, default_value = "32", value_parser = parse_len)]
+    len: usize,
+}
+
+#[derive(Debug, Subcommand)]
+enum Cmd {
+    /// Encodes buffers to base58
+    Encode {
+        /// Format of the input buffers
+        #[arg(short, long, value_enum, default_value_t = Format::Hex)]
+        from: Format,
+
+        /// Input buffers. Read from stdin, one per line, if none are given
+        items: Vec<String>,
+    },
+
+    /// Decodes base58 strings to buffers
+    Decode {
+        /// Format of the output buffers
+        #[arg(short, long, value_enum, default_value_t = Format::Hex)]
+        to: Format,
+
+        /// Input base58 strings. Read from stdin, one per line, if none are given
+        items: Vec<String>,
+    },
+
+    /// Checks that base58 strings are encodings of buffers of the correct length
+    Validate {
+        /// Input base58 strings. Read from stdin, one per line, if none are given
+        items: Vec<String>,
+    },
+}
+
+impl Cmd {
+    fn items(&self) -> &[String] {
+        match self {
+            Self::Encode { items, .. } | Self::Decode { items, .. } | Self::Validate { items } => {
+                items
+            }
+        }
+    }
+}
+
+/// Location of an input item, for error reporting
+#[derive(Debug, Clone, Copy)]
+enum Loc {
+    Arg(usize),
+    Line(usize),
+}
+
+impl std::fmt::Display for Loc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Arg(i) => write!(f, "arg {i}"),
+            Self::Line(i) => write!(f, "line {i}"),
+        }
+    }
+}
+
+/// Returns `Ok(false)` if any item was invalid
+fn run<const BUF_LEN: usize, const MAX_STR_LEN: usize>(
+    cmd: &Cmd,
+    stdin: impl BufRead,
+    out: impl Write,
+    mut err: impl Write,
+) -> io::Result<bool> {
+    let mut out = BufWriter::new(out);
+    let mut all_ok = true;
+
+    let mut handle = |res: Result<[u8; BUF_LEN], (Loc, ItemErr)>| -> io::Result<()> {
+        match (cmd, res) {
+            (_, Err((loc, e))) => {
+                all_ok = false;
+                write!(err, "error: {loc}: {e}")?;
+                if matches!(e, ItemErr::Bs58(Bs58StrDecodeErr::NotOfBufLen)) {
+                    write!(err, " (expected {BUF_LEN} bytes)")?;
+                }
+                writeln!(err)
+            }
+            (Cmd::Encode { .. }, Ok(buf)) => {
+                writeln!(out, "{}", Bs58String::<MAX_STR_LEN>::encode(&buf))
+            }
+            (Cmd::Decode { to, .. }, Ok(buf)) => writeln!(out, "{}", to.format(&buf)),
+            (Cmd::Validate { .. }, Ok(_)) => Ok(()),
+        }
+    };
+
+    let items = cmd.items();
+    match cmd {
+        Cmd::Encode { from, .. } => {
+            let parse = |loc: Loc, s: &str| {
+                from.parse(s.trim())
+                    .and_then(|v| {
+                        // error on wrong len like Bs58String::decode_from() would
+                        v.try_into()
+                            .map_err(|_| ItemErr::Bs58(Bs58StrDecodeErr::NotOfBufLen))
+                    })
+                    .map_err(|e| (loc, e))
+            };
+            if items.is_empty() {
+                for (i, line) in stdin.split(b'\n').enumerate() {
+                    let (line, loc) = (line?, Loc::Line(i + 1));
+                    let line = line.trim_ascii();
+                    if line.is_empty() {
+                        continue;
+                    }
+                    // error on non-utf8 lines like Bs58LineReader would
+                    let res = std::str::from_utf8(line)
+                        .map_err(|e| {
+                            let e = bs58::decode::Error::NonAsciiCharacter {
+                                index: e.valid_up_to(),
+                            };
+                            (loc, ItemErr::Bs58(Bs58StrDecodeErr::Bs58(e)))
+                        })
+                        .and_then(|line| parse(loc, line));
+                    handle(res)?;
+                }
+            } else {
+                for (i, item) in items.iter().enumerate() {
+                    handle(parse(Loc::Arg(i + 1), item))?;
+                }
+            }
+        }
+        Cmd::Decode { .. } | Cmd::Validate { .. } => {
+            if items.is_empty() {
+                for res in Bs58LineReader::<_, MAX_STR_LEN, BUF_LEN>::new(stdin) {
+                    handle(res?.map_err(|(line_no, e)| (Loc::Line(line_no), ItemErr::Bs58(e))))?;
+                }
+            } else {
+                for (i, item) in items.iter().enumerate() {
+                    let res = Bs58Str::<MAX_STR_LEN>::decode_from::<BUF_LEN>(item.trim())
+                        .map(|(_, buf)| buf)
+                        .map_err(|e| (Loc::Arg(i + 1), ItemErr::Bs58(e)));
+                    handle(res)?;
+                }
+            }
+        }
+    }
+
+    out.flush()?;
+    Ok(all_ok)
+}
+
+fn main() -> ExitCode {
+    let Args { cmd, len } = Args::parse();
+    match run_for_len(
+        &cmd,
+        len,
+        io::stdin().lock(),
+        io::stdout().lock(),
+        io::stderr().lock(),
+    ) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONES_HEX: &str = "0101010101010101010101010101010101010101010101010101010101010101";
+    const ONES_BS58: &str = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+    /// Returns (success, stdout, stderr)
+    fn run_cli(args: &[&str], stdin: impl AsRef<[u8]>) -> (bool, String, String) {
+        let Args { cmd, len } =
+            Args::try_parse_from([&"bs58-fixed"].into_iter().chain(args)).unwrap();
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        let ok = run_for_len(&cmd, len, stdin.as_ref(), &mut out, &mut err).unwrap();
+        (
+            ok,
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn encode_decode_args() {
+        assert_eq!(
+            run_cli(&["encode", ONES_HEX], ""),
+            (true, format!("{ONES_BS58}\n"), String::new())
+        );
+        assert_eq!(
+            run_cli(&["decode", "--to", "json", ONES_BS58], ""),
+            (
+                true,
+                format!("{:?}\n", [1u8; 32]).replace(' ', ""),
+                String::new()
+            )
+        );
+        assert_eq!(
+            run_cli(&["validate", ONES_BS58], ""),
+            (true, String::new(), String::new())
+        );
+    }
+
+    #[test]
+    fn stdin() {
+        let stdin = format!("{ONES_HEX}\n\n  {ONES_HEX}  \n");
+        assert_eq!(
+            run_cli(&["encode"], &stdin),
+            (true, format!("{ONES_BS58}\n{ONES_BS58}\n"), String::new())
+        );
+        assert_eq!(
+            run_cli(&["decode", "-l", "32"], format!("{ONES_BS58}\n{ONES_BS58}")),
+            (true, format!("{ONES_HEX}\n{ONES_HEX}\n"), String::new())
+        );
+    }
+
+    #[test]
+    fn reports_all_invalid() {
+        let (ok, out, err) = run_cli(&["decode", "--len", "64", ONES_BS58, "0", ONES_BS58], "");
+        assert!(!ok);
+        assert!(out.is_empty());
+        let lines: Vec<_> = err.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("error: arg 1: "));
+        assert!(lines[0].ends_with("(expected 64 bytes)"));
+        assert!(lines[1].starts_with("error: arg 2: "));
+
+        let (ok, out, err) = run_cli(&["encode", "--from", "base64"], "AQ==\n!\n");
+        assert!(!ok);
+        assert!(out.is_empty());
+        assert!(err.starts_with("error: line 1: "));
+        assert!(err
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("error: line 2: invalid base64"));
+    }
+
+    #[test]
+    fn non_utf8_stdin() {
+        let stdin = [b"\xff\n", ONES_HEX.as_bytes()].concat();
+        let (ok, out, err) = run_cli(&["encode"], stdin);
+        assert!(!ok);
+        assert_eq!(out, format!("{ONES_BS58}\n"));
+        assert_eq!(
+            err,
+            "error: line 1: provided string contained non-ascii character starting at byte 0\n"
+        );
+    }
+
+    #[test]
+    fn unsupported_len() {
+        assert!(Args::try_parse_from(["bs58-fixed", "encode", "--len", "65"]).is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use core::fmt::{Display, Write};
+
+use crate::display::pad;
+
+/// Default number of leading chars kept by the `{:#}` alternate format
+pub const DEFAULT_ABBREV_HEAD: usize = 4;
+
+/// Default number of trailing chars kept by the `{:#}` alternate format
+pub const DEFAULT_ABBREV_TAIL: usize = 3;
+
+/// Char that replaces the elided middle portion of abbreviated strings
+pub const ABBREV_ELLIPSIS: char = '…';
+
+/// ASCII alternative to [`ABBREV_ELLIPSIS`] that is also accepted by [`matches_abbrev`]
+pub const ABBREV_ELLIPSIS_ASCII: &str = "...";
+
+/// Formats a base58 string as its first `head` chars,
+/// followed by [`ABBREV_ELLIPSIS`], followed by its last `tail` chars
+/// e.g. `4vJ9…LKi`.
+///
+/// The full string is formatted instead if abbreviating would not make it shorter.
+///
+/// Respects width, fill and alignment.
+///
+/// Created with [`crate::Bs58Str::abbrev`] or [`crate::Bs58String::abbrev`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bs58Abbrev<'a> {
+    s: &'a str,
+    head: usize,
+    tail: usize,
+}
+
+/// Constructors
+impl<'a> Bs58Abbrev<'a> {
+    /// `s` must be ascii, which is always the case for base58 strings,
+    /// else [`Self::split`] may slice in the middle of a char
+    #[inline]
+    pub(crate) const fn new(s: &'a str, head: usize, tail: usize) -> Self {
+        Self { s, head, tail }
+    }
+}
+
+/// Accessors
+impl<'a> Bs58Abbrev<'a> {
+    /// Returns `(head, tail)` portions of the string,
+    /// or `None` if the string is too short to be abbreviated
+    #[inline]
+    pub fn split(&self) -> Option<(&'a str, &'a str)> {
+        let Self { s, head, tail } = *self;
+        // + 1 for the ellipsis
+        if s.len() <= head.saturating_add(tail).saturating_add(1) {
+            None
+        } else {
+            Some((&s[..head], &s[s.len() - tail..]))
+        }
+    }
+}
+
+impl Display for Bs58Abbrev<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Some((head, tail)) = self.split() else {
+            return pad(f, self.s.len(), |f| f.write_str(self.s));
+        };
+
+        // ascii strs, so byte len == char count
+        pad(f, head.len() + 1 + tail.len(), |f| {
+            f.write_str(head)?;
+            f.write_char(ABBREV_ELLIPSIS)?;
+            f.write_str(tail)
+        })
+    }
+}
+
+/// Checks if the full string `s` matches a user-typed, possibly abbreviated form `abbrev`.
+///
+/// `abbrev` is either:
+/// - the full string, or
+/// - a prefix and suffix of the full string separated by either [`ABBREV_ELLIPSIS`]
+///   or [`ABBREV_ELLIPSIS_ASCII`] e.g. `4vJ9…LKi` or `4vJ9...LKi`.
+///   At least one of the prefix and suffix must be non-empty
+///
+/// Leading and trailing whitespace in `abbrev` is ignored.
+#[inline]
+pub fn matches_abbrev(s: &str, abbrev: &str) -> bool {
+    let abbrev = abbrev.trim();
+    let split = abbrev
+        .split_once(ABBREV_ELLIPSIS)
+        .or_else(|| abbrev.split_once(ABBREV_ELLIPSIS_ASCII));
+    match split {
+        None => s == abbrev,
+        Some(("", "")) => false,
+        Some((head, tail)) => {
+            head.len() + tail.len() <= s.len() && s.starts_with(head) && s.ends_with(tail)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{display, Bs58String};
+
+    const ALL_ONES: &str = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+    #[test]
+    fn abbrev_all_ones() {
+        let a = Bs58Abbrev::new(ALL_ONES, DEFAULT_ABBREV_HEAD, DEFAULT_ABBREV_TAIL);
+        assert_eq!(a.to_string(), "4vJ9…LKi");
+        assert_eq!(format!("{a:>10}"), "  4vJ9…LKi");
+        assert_eq!(format!("{a:-^11}"), "-4vJ9…LKi--");
+
+        for abbrev in [
+            "4vJ9…LKi",
+            "4vJ9...LKi",
+            " 4vJ9…LKi\n",
+            "4vJ9…",
+            "…LKi",
+            ALL_ONES,
+        ] {
+            assert!(matches_abbrev(ALL_ONES, abbrev), "{abbrev}");
+        }
+        for abbrev in [
+            "4vJ8…LKi",
+            "4vJ9…LKj",
+            "4vJ9",
+            "4vJ9..LKi",
+            "",
+            "1…",
+            "…",
+            "...",
+        ] {
+            assert!(!matches_abbrev(ALL_ONES, abbrev), "{abbrev}");
+        }
+    }
+
+    #[test]
+    fn alternate_format() {
+        let s = Bs58String::<44>::encode(&[1u8; 32]);
+        assert_eq!(s.as_str(), ALL_ONES);
+        assert_eq!(format!("{s:#}"), "4vJ9…LKi");
+        // precision is ignored instead of truncating
+        assert_eq!(format!("{s:.3}"), ALL_ONES);
+        assert_eq!(format!("{:#}", s.as_bs58_str()), "4vJ9…LKi");
+        assert_eq!(format!("{:>#9}", display(&[1u8; 32])), " 4vJ9…LKi");
+        assert!(s.matches_abbrev("4vJ9...LKi"));
+    }
+
+    #[test]
+    fn abbrev_too_short() {
+        for s in ["", "1", "4vJ9JU1b"] {
+            let a = Bs58Abbrev::new(s, DEFAULT_ABBREV_HEAD, DEFAULT_ABBREV_TAIL);
+            assert_eq!(a.split(), None);
+            assert_eq!(format!("{a:<9}"), format!("{s:<9}"));
+            assert!(matches_abbrev(s, &a.to_string()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn abbrev_matches_full(
+            buf: [u8; 32],
+            head in 0usize..=50,
+            tail in 0usize..=50,
+            width in 0usize..=50,
+        ) {
+            let s = bs58::encode(buf).into_string();
+            let a = Bs58Abbrev::new(&s, head, tail);
+            let abbrev = a.to_string();
+
+            // a bare ellipsis is not accepted as an abbreviation
+            prop_assert_eq!(matches_abbrev(&s, &abbrev), a.split() != Some(("", "")));
+            prop_assert_eq!(format!("{a:>width$}").chars().count(), abbrev.chars().count().max(width));
+            if let Some((h, t)) = a.split() {
+                prop_assert_eq!(abbrev, format!("{h}{ABBREV_ELLIPSIS}{t}"));
+            } else {
+                prop_assert_eq!(abbrev, s);
+            }
+        }
+    }
+}
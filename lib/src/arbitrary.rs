@@ -0,0 +1,61 @@
+//! [`Bs58String`]s are generated by encoding an arbitrary `[u8; BUF_LEN]`,
+//! so are always valid.
+//!
+//! Like other fixed-size types, missing data is zero-filled instead of erroring.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::Bs58String;
+
+impl<'a, const MAX_STR_LEN: usize> Arbitrary<'a> for Bs58String<MAX_STR_LEN> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // cant do [u8; Self::BUF_LEN] yet, but MAX_STR_LEN >= BUF_LEN
+        let mut buf = [0u8; MAX_STR_LEN];
+        let buf = &mut buf[..Self::BUF_LEN];
+        u.fill_buffer(buf)?;
+
+        // safety: buf is of len Self::BUF_LEN
+        Ok(unsafe { Self::try_encode_slice(buf).unwrap_unchecked() })
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (Self::BUF_LEN, Some(Self::BUF_LEN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+    // disambiguate from proptest's
+    use crate::Bs58Str;
+    use arbitrary::Arbitrary;
+
+    macro_rules! test_suite {
+        ($MAX_STR_LEN:expr, $BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(data in vec(any::<u8>(), 0..=2 * $BUF_LEN)) {
+                    type S = Bs58String<$MAX_STR_LEN>;
+
+                    let s = S::arbitrary(&mut Unstructured::new(&data)).unwrap();
+                    prop_assert_eq!(Bs58Str::try_new(s.as_str()).unwrap(), s.as_bs58_str());
+
+                    // zero-filled if not enough data
+                    let mut expected = [0u8; $BUF_LEN];
+                    let n = data.len().min($BUF_LEN);
+                    expected[..n].copy_from_slice(&data[..n]);
+                    prop_assert_eq!(s.decode(), expected);
+                }
+            }
+        };
+    }
+
+    test_suite!(1, 0, b_1_0_test);
+    test_suite!(4, 2, b_4_2_test);
+    test_suite!(44, 32, b_44_32_test);
+    test_suite!(88, 64, b_88_64_test);
+}
@@ -0,0 +1,74 @@
+//! [`arrayvec`](https://docs.rs/arrayvec) interop:
+//! - [`Bs58String`] and [`Bs58VarString`] convert infallibly into an [`ArrayString`] of the same capacity
+//! - [`ArrayString`]s are validated into [`Bs58Str`]s with [`TryFrom`]
+
+use arrayvec::ArrayString;
+
+use crate::{Bs58Str, Bs58StrDecodeErr, Bs58String, Bs58VarString};
+
+impl<const MAX_STR_LEN: usize> From<Bs58String<MAX_STR_LEN>> for ArrayString<MAX_STR_LEN> {
+    #[inline]
+    fn from(value: Bs58String<MAX_STR_LEN>) -> Self {
+        // safety: value is at most MAX_STR_LEN long
+        unsafe { Self::from(value.as_str()).unwrap_unchecked() }
+    }
+}
+
+impl<const MAX_STR_LEN: usize> From<Bs58VarString<MAX_STR_LEN>> for ArrayString<MAX_STR_LEN> {
+    #[inline]
+    fn from(value: Bs58VarString<MAX_STR_LEN>) -> Self {
+        // safety: value is at most MAX_STR_LEN long
+        unsafe { Self::from(value.as_str()).unwrap_unchecked() }
+    }
+}
+
+impl<'a, const MAX_STR_LEN: usize, const N: usize> TryFrom<&'a ArrayString<N>>
+    for Bs58Str<'a, MAX_STR_LEN>
+{
+    type Error = Bs58StrDecodeErr;
+
+    /// Same as [`Bs58Str::try_new`]
+    #[inline]
+    fn try_from(value: &'a ArrayString<N>) -> Result<Self, Self::Error> {
+        Self::try_new(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+    use crate::proptest::{bs58_string, near_miss};
+
+    macro_rules! test_suite {
+        ($MAX_STR_LEN:expr, $BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(
+                    s in bs58_string::<$MAX_STR_LEN>(),
+                    miss in near_miss($BUF_LEN),
+                    v in vec(any::<u8>(), 0..=$BUF_LEN as usize),
+                ) {
+                    let a: ArrayString<$MAX_STR_LEN> = s.into();
+                    prop_assert_eq!(a.as_str(), s.as_str());
+                    prop_assert_eq!(Bs58Str::<$MAX_STR_LEN>::try_from(&a).unwrap(), s.as_bs58_str());
+
+                    // near misses may be longer than MAX_STR_LEN
+                    if let Ok(miss) = ArrayString::<{ $MAX_STR_LEN + 2 }>::from(miss.as_str()) {
+                        prop_assert!(Bs58Str::<$MAX_STR_LEN>::try_from(&miss).is_err());
+                    }
+
+                    let var = Bs58VarString::<$MAX_STR_LEN>::try_encode_slice(&v).unwrap();
+                    let a: ArrayString<$MAX_STR_LEN> = var.into();
+                    prop_assert_eq!(a.as_str(), var.as_str());
+                }
+            }
+        };
+    }
+
+    test_suite!(2, 1, b_2_1_test);
+    test_suite!(22, 16, b_22_16_test);
+    test_suite!(44, 32, b_44_32_test);
+    test_suite!(88, 64, b_88_64_test);
+}
@@ -0,0 +1,367 @@
+use core::{error::Error, fmt::Display, ops::Deref};
+
+use crate::FixedStrCodec;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Base64StrDecodeErr {
+    NotOfBufLen,
+    InvalidCharacter {
+        character: char,
+        index: usize,
+    },
+    NonAsciiCharacter {
+        index: usize,
+    },
+
+    /// The last symbol before padding has nonzero trailing bits,
+    /// so the string is not the canonical encoding of any buffer
+    InvalidLastSymbol {
+        character: char,
+        index: usize,
+    },
+}
+
+impl Display for Base64StrDecodeErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotOfBufLen => f.write_str("bytes not of correct length"),
+            Self::InvalidCharacter { character, index } => write!(
+                f,
+                "provided string contained invalid character {character:?} at byte {index}"
+            ),
+            Self::NonAsciiCharacter { index } => write!(
+                f,
+                "provided string contained non-ascii character starting at byte {index}"
+            ),
+            Self::InvalidLastSymbol { character, index } => write!(
+                f,
+                "provided string contained invalid last symbol {character:?} at byte {index}"
+            ),
+        }
+    }
+}
+
+impl Error for Base64StrDecodeErr {}
+
+/// A constant-size standard-alphabet, padded base64-encoded string
+/// for encoding of fixed-size buffers.
+///
+/// Decoding is strict: padding is required and non-canonical encodings are rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Base64String<const STR_LEN: usize>([u8; STR_LEN]);
+
+/// Constructors
+impl<const STR_LEN: usize> Base64String<STR_LEN> {
+    /// Same as [`Self::decode_from_onto`], but returns an owned buffer
+    #[inline]
+    pub fn decode_from<const BUF_LEN: usize>(
+        from: &str,
+    ) -> Result<(Self, [u8; BUF_LEN]), Base64StrDecodeErr> {
+        let mut buf = [0u8; BUF_LEN];
+        let res = Self::decode_from_onto(from, &mut buf)?;
+        Ok((res, buf))
+    }
+
+    /// Attempts to create [`Self`] by decoding the given str `from` onto `buf`,
+    /// verifying that it is indeed a base64-encoded buffer of len `BUF_LEN`.
+    ///
+    /// `buf` may be partially overwritten on error
+    #[inline]
+    pub fn decode_from_onto<const BUF_LEN: usize>(
+        from: &str,
+        buf: &mut [u8; BUF_LEN],
+    ) -> Result<Self, Base64StrDecodeErr> {
+        const {
+            assert_base64_lens(BUF_LEN, STR_LEN);
+        }
+
+        let from: &[u8; STR_LEN] = from
+            .as_bytes()
+            .try_into()
+            .map_err(|_| Base64StrDecodeErr::NotOfBufLen)?;
+        decode_checked(from, buf)?;
+        Ok(Self(*from))
+    }
+}
+
+/// Accessors
+impl<const STR_LEN: usize> Base64String<STR_LEN> {
+    #[inline]
+    pub const fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        // safety: base64 alphabet and padding are valid ascii/utf8
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+/// Codec
+impl<const STR_LEN: usize> Base64String<STR_LEN> {
+    #[inline]
+    pub fn encode<const BUF_LEN: usize>(buf: &[u8; BUF_LEN]) -> Self {
+        const {
+            assert_base64_lens(BUF_LEN, STR_LEN);
+        }
+
+        let mut res = Self([b'='; STR_LEN]);
+        for (chunk, out) in buf.chunks(3).zip(res.0.chunks_exact_mut(4)) {
+            let mut group = [0u8; 4];
+            group[1..=chunk.len()].copy_from_slice(chunk);
+            let group = u32::from_be_bytes(group);
+            // n bytes => n + 1 symbols
+            for (j, symbol) in out.iter_mut().enumerate().take(chunk.len() + 1) {
+                *symbol = ALPHABET[((group >> (18 - 6 * j)) & 0x3f) as usize];
+            }
+        }
+        res
+    }
+
+    #[inline]
+    pub fn decode<const BUF_LEN: usize>(&self) -> [u8; BUF_LEN] {
+        let mut buf = [0u8; BUF_LEN];
+        self.decode_onto(&mut buf);
+        buf
+    }
+
+    /// Decodes `self` onto `buf`, overwriting previous data
+    #[inline]
+    pub fn decode_onto<const BUF_LEN: usize>(&self, buf: &mut [u8; BUF_LEN]) {
+        const {
+            assert_base64_lens(BUF_LEN, STR_LEN);
+        }
+
+        // safety: self is always a valid encoding of a BUF_LEN-byte buffer
+        unsafe { decode_checked(&self.0, buf).unwrap_unchecked() }
+    }
+}
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[inline]
+const fn decode_symbol(c: u8) -> Option<u32> {
+    let v = match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return None,
+    };
+    Some(v as u32)
+}
+
+/// Lens are checked at compile time by callers
+#[inline]
+fn decode_checked<const BUF_LEN: usize, const STR_LEN: usize>(
+    from: &[u8; STR_LEN],
+    buf: &mut [u8; BUF_LEN],
+) -> Result<(), Base64StrDecodeErr> {
+    let n_symbols = STR_LEN - (3 - BUF_LEN % 3) % 3;
+
+    let mut acc = 0u32;
+    let mut n_bits = 0;
+    let mut n_bytes = 0;
+    for (index, c) in from.iter().copied().enumerate() {
+        let invalid_char = || {
+            if c.is_ascii() {
+                Base64StrDecodeErr::InvalidCharacter {
+                    character: char::from(c),
+                    index,
+                }
+            } else {
+                Base64StrDecodeErr::NonAsciiCharacter { index }
+            }
+        };
+        if index >= n_symbols {
+            if c != b'=' {
+                return Err(invalid_char());
+            }
+            continue;
+        }
+
+        acc = (acc << 6) | decode_symbol(c).ok_or_else(invalid_char)?;
+        n_bits += 6;
+        if n_bits >= 8 {
+            n_bits -= 8;
+            buf[n_bytes] = (acc >> n_bits) as u8;
+            n_bytes += 1;
+            acc &= (1 << n_bits) - 1;
+        }
+    }
+
+    if acc != 0 {
+        let index = n_symbols - 1;
+        return Err(Base64StrDecodeErr::InvalidLastSymbol {
+            character: char::from(from[index]),
+            index,
+        });
+    }
+    Ok(())
+}
+
+/// The length of the padded base64 encoding of a `BUF_LEN`-byte buffer
+pub const fn base64_str_len(buf_len: usize) -> usize {
+    buf_len.div_ceil(3) * 4
+}
+
+/// Panics (at compile-time, when called in a `const {}` block)
+/// if `STR_LEN`-long base64 strings do not encode `BUF_LEN`-byte buffers,
+/// i.e. if `base64_str_len(BUF_LEN) != STR_LEN`.
+#[inline]
+pub const fn assert_base64_lens(buf_len: usize, str_len: usize) {
+    assert!(
+        base64_str_len(buf_len) == str_len,
+        "base64_str_len(BUF_LEN) != STR_LEN. Use `bs58_fixed::base64_str_len(BUF_LEN)` as STR_LEN"
+    );
+}
+
+impl<const BUF_LEN: usize, const STR_LEN: usize> FixedStrCodec<BUF_LEN> for Base64String<STR_LEN> {
+    type DecodeErr = Base64StrDecodeErr;
+
+    const NAME: &'static str = "base64";
+
+    #[inline]
+    fn encode(buf: &[u8; BUF_LEN]) -> Self {
+        Base64String::encode(buf)
+    }
+
+    #[inline]
+    fn decode_from(from: &str) -> Result<(Self, [u8; BUF_LEN]), Self::DecodeErr> {
+        Base64String::decode_from(from)
+    }
+
+    #[inline]
+    fn decode(&self) -> [u8; BUF_LEN] {
+        Base64String::decode(self)
+    }
+}
+
+// core traits
+
+impl<const STR_LEN: usize> Deref for Base64String<STR_LEN> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const STR_LEN: usize> AsRef<str> for Base64String<STR_LEN> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const STR_LEN: usize> AsRef<[u8]> for Base64String<STR_LEN> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const STR_LEN: usize> Display for Base64String<STR_LEN> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::base64::{engine::general_purpose::STANDARD, Engine};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    macro_rules! test_suite {
+        ($BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(buf: [u8; $BUF_LEN]) {
+                    type S = Base64String<{ base64_str_len($BUF_LEN) }>;
+
+                    // check against base64 impl
+                    let base64_impl = STANDARD.encode(buf);
+                    let encoded = S::encode(&buf);
+                    prop_assert_eq!(encoded.as_str(), base64_impl.as_str());
+                    prop_assert_eq!(encoded.decode::<$BUF_LEN>(), buf);
+
+                    let (s, decoded) = S::decode_from::<$BUF_LEN>(&base64_impl).unwrap();
+                    prop_assert_eq!(s, encoded);
+                    prop_assert_eq!(decoded, buf);
+
+                    // missing padding / too long
+                    if $BUF_LEN % 3 != 0 {
+                        prop_assert_eq!(
+                            S::decode_from::<$BUF_LEN>(base64_impl.trim_end_matches('=')).unwrap_err(),
+                            Base64StrDecodeErr::NotOfBufLen
+                        );
+                    }
+                    prop_assert_eq!(
+                        S::decode_from::<$BUF_LEN>(&format!("{base64_impl}AAAA")).unwrap_err(),
+                        Base64StrDecodeErr::NotOfBufLen
+                    );
+                }
+            }
+        };
+    }
+
+    test_suite!(1, b_1_test);
+    test_suite!(2, b_2_test);
+    test_suite!(3, b_3_test);
+    test_suite!(16, b_16_test);
+    test_suite!(20, b_20_test);
+    test_suite!(32, b_32_test);
+    test_suite!(64, b_64_test);
+
+    #[test]
+    fn empty() {
+        assert_eq!(Base64String::<0>::encode(&[]).as_str(), "");
+        assert_eq!(Base64String::<0>::decode_from::<0>("").unwrap().1, [0u8; 0]);
+    }
+
+    #[test]
+    fn invalid() {
+        type S = Base64String<4>;
+
+        assert_eq!(
+            S::decode_from::<1>("AQ=A").unwrap_err(),
+            Base64StrDecodeErr::InvalidCharacter {
+                character: 'A',
+                index: 3
+            }
+        );
+        assert_eq!(
+            S::decode_from::<2>("AQ==").unwrap_err(),
+            Base64StrDecodeErr::InvalidCharacter {
+                character: '=',
+                index: 2
+            }
+        );
+        assert_eq!(
+            S::decode_from::<1>("A-==").unwrap_err(),
+            Base64StrDecodeErr::InvalidCharacter {
+                character: '-',
+                index: 1
+            }
+        );
+        assert_eq!(
+            S::decode_from::<1>("é==").unwrap_err(),
+            Base64StrDecodeErr::NonAsciiCharacter { index: 0 }
+        );
+        // "AQ==" is the canonical encoding of [1]
+        assert_eq!(
+            S::decode_from::<1>("AR==").unwrap_err(),
+            Base64StrDecodeErr::InvalidLastSymbol {
+                character: 'R',
+                index: 1
+            }
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use core::{error::Error, fmt::Display, ops::Deref};
 
-use crate::buf_len;
+use crate::{assert_lens, buf_len, display::fmt_bs58_str, matches_abbrev, Bs58Abbrev};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Bs58StrDecodeErr {
@@ -30,6 +30,27 @@ pub struct Bs58Str<'a, const MAX_STR_LEN: usize>(pub(crate) &'a str);
 
 /// Constructors
 impl<'a, const MAX_STR_LEN: usize> Bs58Str<'a, MAX_STR_LEN> {
+    /// Attempts to create [`Self`] from the given str `from`,
+    /// verifying that it is indeed a base58-encoded buffer of the correct size
+    /// and discarding the decoded bytes.
+    ///
+    /// Useful in contexts generic over `MAX_STR_LEN` that cannot name `BUF_LEN`
+    #[inline]
+    pub fn try_new(from: &'a str) -> Result<Self, Bs58StrDecodeErr> {
+        // cant do [u8; Self::BUF_LEN] yet, but MAX_STR_LEN >= BUF_LEN
+        let mut buf = [0u8; MAX_STR_LEN];
+        let buf = &mut buf[..Self::BUF_LEN];
+
+        let len = bs58::decode(from)
+            .onto(buf)
+            .map_err(Bs58StrDecodeErr::Bs58)?;
+        if len != Self::BUF_LEN {
+            Err(Bs58StrDecodeErr::NotOfBufLen)
+        } else {
+            Ok(Self(from))
+        }
+    }
+
     /// Same as [`Self::decode_from_onto`], but returns an owned buffer
     #[inline]
     pub fn decode_from<const BUF_LEN: usize>(
@@ -54,7 +75,7 @@ impl<'a, const MAX_STR_LEN: usize> Bs58Str<'a, MAX_STR_LEN> {
         buf: &mut [u8; BUF_LEN],
     ) -> Result<Self, Bs58StrDecodeErr> {
         const {
-            assert!(BUF_LEN == Self::BUF_LEN);
+            assert_lens(BUF_LEN, MAX_STR_LEN);
         }
 
         let len = bs58::decode(from)
@@ -81,6 +102,20 @@ impl<const MAX_STR_LEN: usize> Bs58Str<'_, MAX_STR_LEN> {
     }
 }
 
+/// Abbreviation
+impl<'a, const MAX_STR_LEN: usize> Bs58Str<'a, MAX_STR_LEN> {
+    #[inline]
+    pub const fn abbrev(&self, head: usize, tail: usize) -> Bs58Abbrev<'a> {
+        Bs58Abbrev::new(self.0, head, tail)
+    }
+
+    /// See [`matches_abbrev`]
+    #[inline]
+    pub fn matches_abbrev(&self, abbrev: &str) -> bool {
+        matches_abbrev(self.as_str(), abbrev)
+    }
+}
+
 /// Decode
 impl<const MAX_STR_LEN: usize> Bs58Str<'_, MAX_STR_LEN> {
     pub const BUF_LEN: usize = buf_len(MAX_STR_LEN);
@@ -102,7 +137,7 @@ impl<const MAX_STR_LEN: usize> Bs58Str<'_, MAX_STR_LEN> {
     #[inline]
     pub fn decode_onto<const BUF_LEN: usize>(&self, buf: &mut [u8; BUF_LEN]) {
         const {
-            assert!(BUF_LEN == Self::BUF_LEN);
+            assert_lens(BUF_LEN, MAX_STR_LEN);
         }
 
         // safety: len checked at compile time above, so will not error with BufferTooSmall
@@ -114,6 +149,16 @@ impl<const MAX_STR_LEN: usize> Bs58Str<'_, MAX_STR_LEN> {
 
 // core traits
 
+impl<'a, const MAX_STR_LEN: usize> TryFrom<&'a str> for Bs58Str<'a, MAX_STR_LEN> {
+    type Error = Bs58StrDecodeErr;
+
+    /// See [`Bs58Str::try_new`]
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
 impl<const MAX_STR_LEN: usize> Deref for Bs58Str<'_, MAX_STR_LEN> {
     type Target = str;
 
@@ -137,11 +182,24 @@ impl<const MAX_STR_LEN: usize> AsRef<[u8]> for Bs58Str<'_, MAX_STR_LEN> {
     }
 }
 
+impl<const MAX_STR_LEN: usize> Display for Bs58Str<'_, MAX_STR_LEN> {
+    /// Respects width, fill and alignment.
+    /// Precision is ignored, so the encoding is never truncated.
+    ///
+    /// The alternate flag `{:#}` formats the abbreviated form
+    /// with [`crate::DEFAULT_ABBREV_HEAD`] and [`crate::DEFAULT_ABBREV_TAIL`] e.g. `4vJ9…LKi`
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_bs58_str(self.as_str(), f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::{collection::vec, prelude::*};
 
     use super::*;
+    use crate::proptest::near_miss;
 
     macro_rules! test_suite {
         ($MAX_STR_LEN:expr, $BUF_LEN:expr, $test_name:ident) => {
@@ -149,6 +207,7 @@ mod tests {
                 #[test]
                 fn $test_name(
                     v in vec(any::<u8>(), 0..=2 * $BUF_LEN),
+                    miss in near_miss($BUF_LEN),
                 ) {
                     type S<'a> = Bs58Str<'a, $MAX_STR_LEN>;
 
@@ -158,9 +217,13 @@ mod tests {
                         let (s, buf) = us_res.unwrap();
                         prop_assert_eq!(s.as_str(), &bs58_impl);
                         prop_assert_eq!(buf.as_slice(), v.as_slice());
+                        prop_assert_eq!(S::try_new(&bs58_impl).unwrap(), s);
                     } else {
-                        us_res.unwrap_err();
+                        prop_assert_eq!(S::try_new(&bs58_impl).unwrap_err(), us_res.unwrap_err());
                     }
+
+                    S::decode_from::<$BUF_LEN>(&miss).unwrap_err();
+                    S::try_new(&miss).unwrap_err();
                 }
             }
         };
@@ -0,0 +1,91 @@
+//! Borsh serializes [`Bs58String`] as its decoded `[u8; BUF_LEN]`.
+//!
+//! The empty [`Bs58String::new`] serializes as all zeros like `[0u8; BUF_LEN]`,
+//! so it deserializes to the non-empty encoding of `[0u8; BUF_LEN]` i.e. `BUF_LEN` `'1'`s
+
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::Bs58String;
+
+impl<const MAX_STR_LEN: usize> BorshSerialize for Bs58String<MAX_STR_LEN> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        // cant do [u8; Self::BUF_LEN] yet, but MAX_STR_LEN >= BUF_LEN
+        let mut buf = [0u8; MAX_STR_LEN];
+        let buf = &mut buf[..Self::BUF_LEN];
+
+        // safety: self is either empty or a valid base58-encoded str of
+        // a buffer of the correct length, so will not error with BufferTooSmall
+        unsafe {
+            bs58::decode(self.as_slice())
+                .onto(&mut *buf)
+                .unwrap_unchecked();
+        }
+        writer.write_all(buf)
+    }
+}
+
+impl<const MAX_STR_LEN: usize> BorshDeserialize for Bs58String<MAX_STR_LEN> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        // cant do [u8; Self::BUF_LEN] yet, but MAX_STR_LEN >= BUF_LEN
+        let mut buf = [0u8; MAX_STR_LEN];
+        let buf = &mut buf[..Self::BUF_LEN];
+        reader.read_exact(buf)?;
+
+        let mut res = Self::new();
+        // safety: buf is of len Self::BUF_LEN
+        unsafe {
+            bs58::encode(buf).onto(&mut res).unwrap_unchecked();
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    macro_rules! test_suite {
+        ($MAX_STR_LEN:expr, $BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(buf: [u8; $BUF_LEN]) {
+                    type S = Bs58String<$MAX_STR_LEN>;
+
+                    let s = S::encode(&buf);
+                    let ser = borsh::to_vec(&s).unwrap();
+                    prop_assert_eq!(ser.as_slice(), buf.as_slice());
+                    prop_assert_eq!(S::try_from_slice(&ser).unwrap(), s);
+
+                    // too short
+                    if $BUF_LEN > 0 {
+                        S::try_from_slice(&ser[1..]).unwrap_err();
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn empty_deserializes_to_zeros() {
+        let ser = borsh::to_vec(&Bs58String::<44>::new()).unwrap();
+        assert_eq!(ser, [0u8; 32]);
+        assert_eq!(
+            Bs58String::<44>::try_from_slice(&ser).unwrap(),
+            Bs58String::<44>::encode(&[0u8; 32])
+        );
+    }
+
+    test_suite!(0, 0, b_0_0_test);
+    test_suite!(1, 0, b_1_0_test);
+    test_suite!(4, 2, b_4_2_test);
+    test_suite!(22, 16, b_22_16_test);
+    test_suite!(44, 32, b_44_32_test);
+    test_suite!(88, 64, b_88_64_test);
+}
@@ -0,0 +1,91 @@
+//! [`FixedStrCodec`], for code that should work with
+//! [`Bs58String`], [`crate::HexString`] and [`crate::Base64String`] alike
+
+use core::{error::Error, ops::Deref};
+
+use crate::{Bs58Str, Bs58StrDecodeErr, Bs58String};
+
+/// A fixed-capacity, no-alloc string encoding of `[u8; BUF_LEN]`s.
+///
+/// Implementors are guaranteed to always hold a valid encoding of a `BUF_LEN`-byte buffer,
+/// so [`Self::decode`] is infallible.
+pub trait FixedStrCodec<const BUF_LEN: usize>: Sized + Copy + Deref<Target = str> {
+    type DecodeErr: Error;
+
+    /// Name of the encoding e.g. `"base58"`, for error messages
+    const NAME: &'static str;
+
+    fn encode(buf: &[u8; BUF_LEN]) -> Self;
+
+    /// Attempts to create [`Self`] by decoding the given str `from`,
+    /// verifying that it is indeed a valid encoding of a `BUF_LEN`-byte buffer
+    fn decode_from(from: &str) -> Result<(Self, [u8; BUF_LEN]), Self::DecodeErr>;
+
+    fn decode(&self) -> [u8; BUF_LEN];
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> FixedStrCodec<BUF_LEN>
+    for Bs58String<MAX_STR_LEN>
+{
+    type DecodeErr = Bs58StrDecodeErr;
+
+    const NAME: &'static str = "base58";
+
+    #[inline]
+    fn encode(buf: &[u8; BUF_LEN]) -> Self {
+        Bs58String::encode(buf)
+    }
+
+    #[inline]
+    fn decode_from(from: &str) -> Result<(Self, [u8; BUF_LEN]), Self::DecodeErr> {
+        let (s, buf) = Bs58Str::<MAX_STR_LEN>::decode_from(from)?;
+        Ok((s.into(), buf))
+    }
+
+    #[inline]
+    fn decode(&self) -> [u8; BUF_LEN] {
+        Bs58String::decode(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{Base64String, HexString};
+
+    /// Generic round-trip, to make sure the impls are usable in generic contexts
+    fn round_trip<C: FixedStrCodec<BUF_LEN>, const BUF_LEN: usize>(buf: &[u8; BUF_LEN]) -> C {
+        let encoded = C::encode(buf);
+        assert_eq!(&encoded.decode(), buf);
+        let (decoded_s, decoded) = C::decode_from(&encoded).unwrap();
+        assert_eq!(&decoded, buf);
+        assert_eq!(&*decoded_s, &*encoded);
+        encoded
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip_32(buf: [u8; 32]) {
+            let bs58 = round_trip::<Bs58String<44>, 32>(&buf);
+            prop_assert_eq!(bs58.as_str(), bs58::encode(buf).into_string());
+            round_trip::<HexString<64>, 32>(&buf);
+            round_trip::<Base64String<44>, 32>(&buf);
+        }
+
+        #[test]
+        fn round_trip_64(buf: [u8; 64]) {
+            round_trip::<Bs58String<88>, 64>(&buf);
+            round_trip::<HexString<128>, 64>(&buf);
+            round_trip::<Base64String<88>, 64>(&buf);
+        }
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(<Bs58String<44> as FixedStrCodec<32>>::NAME, "base58");
+        assert_eq!(<HexString<64> as FixedStrCodec<32>>::NAME, "hex");
+        assert_eq!(<Base64String<44> as FixedStrCodec<32>>::NAME, "base64");
+    }
+}
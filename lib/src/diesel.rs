@@ -0,0 +1,85 @@
+//! [`Bs58String`] is written as [`Text`],
+//! or as [`Binary`] of the decoded `[u8; BUF_LEN]` through [`Bs58String::to_blob`].
+//!
+//! It can be read from either a [`Text`] column containing the base58 string
+//! or a [`Binary`] column containing the decoded `[u8; BUF_LEN]`.
+
+use alloc::{string::String, vec::Vec};
+
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    serialize::{self, Output, ToSql},
+    sql_types::{Binary, Text},
+};
+
+use crate::{Bs58Blob, Bs58Str, Bs58String};
+
+/// Only exists to derive `AsExpression<Text>` and `FromSqlRow` for [`Bs58String`]
+/// without putting diesel attributes on the struct definition
+#[allow(dead_code)]
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive, sql_type = Text)]
+struct Bs58StringProxy<const MAX_STR_LEN: usize>(Bs58String<MAX_STR_LEN>);
+
+/// Only exists to derive `AsExpression<Binary>` for [`Bs58Blob`]
+#[allow(dead_code)]
+#[derive(AsExpression)]
+#[diesel(foreign_derive, sql_type = Binary)]
+struct Bs58BlobProxy<const BUF_LEN: usize>(Bs58Blob<BUF_LEN>);
+
+impl<const MAX_STR_LEN: usize, DB: Backend> ToSql<Text, DB> for Bs58String<MAX_STR_LEN>
+where
+    str: ToSql<Text, DB>,
+{
+    #[inline]
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.as_str().to_sql(out)
+    }
+}
+
+impl<const BUF_LEN: usize, DB: Backend> ToSql<Binary, DB> for Bs58Blob<BUF_LEN>
+where
+    [u8]: ToSql<Binary, DB>,
+{
+    #[inline]
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.as_slice().to_sql(out)
+    }
+}
+
+impl<const MAX_STR_LEN: usize, DB: Backend> FromSql<Text, DB> for Bs58String<MAX_STR_LEN>
+where
+    String: FromSql<Text, DB>,
+{
+    #[inline]
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let s = String::from_sql(bytes)?;
+        Ok(Bs58Str::try_new(&s)?.into())
+    }
+}
+
+impl<const MAX_STR_LEN: usize, DB: Backend> FromSql<Binary, DB> for Bs58String<MAX_STR_LEN>
+where
+    Vec<u8>: FromSql<Binary, DB>,
+{
+    #[inline]
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let b = Vec::from_sql(bytes)?;
+        Ok(Self::try_encode_slice(&b)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sql_tests::test_suite! {
+        diesel,
+        Bs58String<MAX_STR_LEN>,
+        new: |buf| Bs58String::encode(&buf),
+        text: |s| *s,
+        blob: |s| s.to_blob::<BUF_LEN>(),
+    }
+}
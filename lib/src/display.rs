@@ -0,0 +1,155 @@
+use core::fmt::{Alignment, Display, Formatter, Write};
+
+use crate::{max_str_len, Bs58Abbrev, DEFAULT_ABBREV_HEAD, DEFAULT_ABBREV_TAIL};
+
+/// Lazily base58-encodes a `[u8; BUF_LEN]` only when it is formatted,
+/// without materializing a [`crate::Bs58String`] first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Bs58Display<'a, const BUF_LEN: usize>(pub &'a [u8; BUF_LEN]);
+
+/// Shorthand for [`Bs58Display::new`]
+///
+/// ```
+/// let s = format!("{}", bs58_fixed::display(&[0xFF; 32]));
+/// assert_eq!(s, "JEKNVnkbo3jma5nREBBJCDoXFVeKkD56V3xKrvRmWxFG");
+/// ```
+#[inline]
+pub const fn display<const BUF_LEN: usize>(buf: &[u8; BUF_LEN]) -> Bs58Display<'_, BUF_LEN> {
+    Bs58Display::new(buf)
+}
+
+/// Constructors
+impl<'a, const BUF_LEN: usize> Bs58Display<'a, BUF_LEN> {
+    #[inline]
+    pub const fn new(buf: &'a [u8; BUF_LEN]) -> Self {
+        Self(buf)
+    }
+}
+
+/// Encode
+impl<const BUF_LEN: usize> Bs58Display<'_, BUF_LEN> {
+    /// Encodes onto the start of caller-owned `out`, returning the encoded str.
+    ///
+    /// `out` needs to be at least `max_str_len(BUF_LEN)` long to be able
+    /// to encode all possible buffers
+    ///
+    /// Returns [`bs58::encode::Error::BufferTooSmall`] if `out` is too small
+    /// to contain the encoding of this buffer
+    #[inline]
+    pub fn encode_into<'b>(&self, out: &'b mut [u8]) -> Result<&'b str, bs58::encode::Error> {
+        let len = bs58::encode(self.0).onto(&mut *out)?;
+        // safety: bs58 alphabet is valid ascii/utf8
+        Ok(unsafe { core::str::from_utf8_unchecked(&out[..len]) })
+    }
+
+    /// Encodes straight into `w`.
+    ///
+    /// Unlike formatting with [`Display`], this ignores formatting options
+    #[inline]
+    pub fn write_to<W: Write>(&self, w: &mut W) -> core::fmt::Result {
+        self.with_encoded(|s| w.write_str(s))
+    }
+
+    /// Encodes onto a stack buffer and calls `f` with the encoded str
+    #[inline]
+    fn with_encoded<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        const {
+            assert!(max_str_len(BUF_LEN) <= 2 * BUF_LEN);
+        }
+
+        // cant do [u8; max_str_len(BUF_LEN)] yet, but 2 * BUF_LEN >= max_str_len(BUF_LEN)
+        let mut out = [[0u8; 2]; BUF_LEN];
+        // safety: out is at least max_str_len(BUF_LEN) long, checked at compile time above
+        let s = unsafe { self.encode_into(out.as_flattened_mut()).unwrap_unchecked() };
+        f(s)
+    }
+}
+
+impl<const BUF_LEN: usize> Display for Bs58Display<'_, BUF_LEN> {
+    /// Same as [`crate::Bs58String`]'s [`Display`] impl
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.with_encoded(|s| fmt_bs58_str(s, f))
+    }
+}
+
+/// [`Display`] impl shared by all base58 string types
+#[inline]
+pub(crate) fn fmt_bs58_str(s: &str, f: &mut Formatter<'_>) -> core::fmt::Result {
+    if f.alternate() {
+        Bs58Abbrev::new(s, DEFAULT_ABBREV_HEAD, DEFAULT_ABBREV_TAIL).fmt(f)
+    } else {
+        pad(f, s.len(), |f| f.write_str(s))
+    }
+}
+
+/// Like [`Formatter::pad`], but only respects width, fill and alignment.
+///
+/// Precision is ignored so that encodings are never truncated.
+/// `len` is the number of chars `write` writes.
+#[inline]
+pub(crate) fn pad(
+    f: &mut Formatter<'_>,
+    len: usize,
+    write: impl FnOnce(&mut Formatter<'_>) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let padding = f.width().map_or(0, |w| w.saturating_sub(len));
+    let (pre, post) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        Some(Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+
+    (0..pre).try_for_each(|_| f.write_char(fill))?;
+    write(f)?;
+    (0..post).try_for_each(|_| f.write_char(fill))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    macro_rules! test_suite {
+        ($BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(buf: [u8; $BUF_LEN], width in 0usize..=100) {
+                    let bs58_impl = bs58::encode(buf).into_string();
+                    let d = display(&buf);
+
+                    prop_assert_eq!(d.to_string(), bs58_impl.as_str());
+                    prop_assert_eq!(
+                        format!("{d:*^width$}"),
+                        format!("{bs58_impl:*^width$}")
+                    );
+
+                    let mut written = String::new();
+                    d.write_to(&mut written).unwrap();
+                    prop_assert_eq!(written.as_str(), bs58_impl.as_str());
+
+                    // precision does not truncate
+                    prop_assert_eq!(format!("{d:.3}"), bs58_impl.as_str());
+
+                    let mut out = [0u8; 200];
+                    prop_assert_eq!(d.encode_into(&mut out).unwrap(), bs58_impl.as_str());
+                    prop_assert_eq!(
+                        d.encode_into(&mut out[..bs58_impl.len().saturating_sub(1)]).is_err(),
+                        !bs58_impl.is_empty()
+                    );
+                }
+            }
+        };
+    }
+
+    test_suite!(0, b_0_test);
+    test_suite!(1, b_1_test);
+    test_suite!(16, b_16_test);
+    test_suite!(32, b_32_test);
+    test_suite!(64, b_64_test);
+    // not a predefined len
+    test_suite!(100, b_100_test);
+}
@@ -0,0 +1,106 @@
+//! [`ed25519_dalek`](https://docs.rs/ed25519-dalek) interop:
+//! - [`VerifyingKey`]s encode into [`Bs58String<44>`]s
+//! - [`Signature`]s encode into [`Bs58String<88>`]s
+//! - [`Bs58Str`]s and [`Bs58String`]s decode into [`Signature`]s infallibly,
+//!   and into [`VerifyingKey`]s with [`TryFrom`], which fails if the key is not on the curve
+
+use ed25519_dalek::{Signature, SignatureError, VerifyingKey};
+
+use crate::{Bs58Str, Bs58String};
+
+impl From<&VerifyingKey> for Bs58String<44> {
+    #[inline]
+    fn from(value: &VerifyingKey) -> Self {
+        Self::encode(value.as_bytes())
+    }
+}
+
+impl From<VerifyingKey> for Bs58String<44> {
+    #[inline]
+    fn from(value: VerifyingKey) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl TryFrom<Bs58Str<'_, 44>> for VerifyingKey {
+    type Error = SignatureError;
+
+    /// Errors if the decoded bytes are not a valid compressed ed25519 point
+    #[inline]
+    fn try_from(value: Bs58Str<'_, 44>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&value.decode())
+    }
+}
+
+impl TryFrom<&Bs58String<44>> for VerifyingKey {
+    type Error = SignatureError;
+
+    /// Errors if the decoded bytes are not a valid compressed ed25519 point
+    #[inline]
+    fn try_from(value: &Bs58String<44>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_bs58_str())
+    }
+}
+
+impl From<&Signature> for Bs58String<88> {
+    #[inline]
+    fn from(value: &Signature) -> Self {
+        Self::encode(&value.to_bytes())
+    }
+}
+
+impl From<Signature> for Bs58String<88> {
+    #[inline]
+    fn from(value: Signature) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<Bs58Str<'_, 88>> for Signature {
+    #[inline]
+    fn from(value: Bs58Str<'_, 88>) -> Self {
+        Self::from_bytes(&value.decode())
+    }
+}
+
+impl From<&Bs58String<88>> for Signature {
+    #[inline]
+    fn from(value: &Bs58String<88>) -> Self {
+        Self::from(value.as_bs58_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer, SigningKey, Verifier};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(secret: [u8; 32], msg: Vec<u8>) {
+            let signing_key = SigningKey::from_bytes(&secret);
+            let vk = signing_key.verifying_key();
+            let sig = signing_key.sign(&msg);
+
+            let vk_str = Bs58String::<44>::from(vk);
+            prop_assert_eq!(vk_str.as_str(), bs58::encode(vk.as_bytes()).into_string());
+            let sig_str = Bs58String::<88>::from(sig);
+            prop_assert_eq!(sig_str.as_str(), bs58::encode(sig.to_bytes()).into_string());
+
+            let vk = VerifyingKey::try_from(&vk_str).unwrap();
+            let sig = Signature::from(&sig_str);
+            prop_assert!(vk.verify(&msg, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn off_curve() {
+        // y = 2 is not the y-coordinate of any point on the curve
+        let mut off_curve = [0u8; 32];
+        off_curve[0] = 2;
+        let s = Bs58String::<44>::encode(&off_curve);
+        assert!(VerifyingKey::try_from(&s).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! [`heapless`](https://docs.rs/heapless) interop:
+//! - [`Bs58String`] and [`Bs58VarString`] convert infallibly into a [`String`] of the same capacity
+//! - [`String`]s are validated into [`Bs58Str`]s with [`TryFrom`]
+//! - [`Bs58VarString`] decodes into a [`Vec`]
+
+use heapless::{String, Vec};
+
+use crate::{Bs58Str, Bs58StrDecodeErr, Bs58String, Bs58VarString};
+
+impl<const MAX_STR_LEN: usize> From<Bs58String<MAX_STR_LEN>> for String<MAX_STR_LEN> {
+    #[inline]
+    fn from(value: Bs58String<MAX_STR_LEN>) -> Self {
+        // safety: value is at most MAX_STR_LEN long
+        unsafe { Self::try_from(value.as_str()).unwrap_unchecked() }
+    }
+}
+
+impl<const MAX_STR_LEN: usize> From<Bs58VarString<MAX_STR_LEN>> for String<MAX_STR_LEN> {
+    #[inline]
+    fn from(value: Bs58VarString<MAX_STR_LEN>) -> Self {
+        // safety: value is at most MAX_STR_LEN long
+        unsafe { Self::try_from(value.as_str()).unwrap_unchecked() }
+    }
+}
+
+impl<'a, const MAX_STR_LEN: usize, const N: usize> TryFrom<&'a String<N>>
+    for Bs58Str<'a, MAX_STR_LEN>
+{
+    type Error = Bs58StrDecodeErr;
+
+    /// Same as [`Bs58Str::try_new`]
+    #[inline]
+    fn try_from(value: &'a String<N>) -> Result<Self, Self::Error> {
+        Self::try_new(value.as_str())
+    }
+}
+
+/// heapless
+impl<const MAX_STR_LEN: usize> Bs58VarString<MAX_STR_LEN> {
+    /// Same as [`Self::decode_from`], but returns the decoded bytes as a [`Vec`]
+    #[inline]
+    pub fn decode_from_heapless<const BUF_LEN: usize>(
+        from: &str,
+    ) -> Result<(Self, Vec<u8, BUF_LEN>), Bs58StrDecodeErr> {
+        let (res, len, buf) = Self::decode_from::<BUF_LEN>(from)?;
+        let mut v = Vec::from_array(buf);
+        v.truncate(len);
+        Ok((res, v))
+    }
+
+    /// Same as [`Self::decode`], but returns the decoded bytes as a [`Vec`]
+    #[inline]
+    pub fn decode_heapless<const BUF_LEN: usize>(&self) -> Vec<u8, BUF_LEN> {
+        let (len, buf) = self.decode::<BUF_LEN>();
+        let mut v = Vec::from_array(buf);
+        v.truncate(len);
+        v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+    use crate::proptest::{bs58_string, near_miss};
+
+    macro_rules! test_suite {
+        ($MAX_STR_LEN:expr, $BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(
+                    s in bs58_string::<$MAX_STR_LEN>(),
+                    miss in near_miss($BUF_LEN),
+                    v in vec(any::<u8>(), 0..=$BUF_LEN as usize),
+                ) {
+                    let h: String<$MAX_STR_LEN> = s.into();
+                    prop_assert_eq!(h.as_str(), s.as_str());
+                    prop_assert_eq!(Bs58Str::<$MAX_STR_LEN>::try_from(&h).unwrap(), s.as_bs58_str());
+
+                    // near misses may be longer than MAX_STR_LEN
+                    if let Ok(miss) = String::<{ $MAX_STR_LEN + 2 }>::try_from(miss.as_str()) {
+                        prop_assert!(Bs58Str::<$MAX_STR_LEN>::try_from(&miss).is_err());
+                    }
+
+                    let var = Bs58VarString::<$MAX_STR_LEN>::try_encode_slice(&v).unwrap();
+                    let h: String<$MAX_STR_LEN> = var.into();
+                    prop_assert_eq!(h.as_str(), var.as_str());
+                    prop_assert_eq!(&var.decode_heapless::<$BUF_LEN>(), v.as_slice());
+                    let (s, decoded) =
+                        Bs58VarString::<$MAX_STR_LEN>::decode_from_heapless::<$BUF_LEN>(&h).unwrap();
+                    prop_assert_eq!(s, var);
+                    prop_assert_eq!(decoded.as_slice(), v.as_slice());
+                }
+            }
+        };
+    }
+
+    test_suite!(2, 1, b_2_1_test);
+    test_suite!(22, 16, b_22_16_test);
+    test_suite!(44, 32, b_44_32_test);
+    test_suite!(88, 64, b_88_64_test);
+}
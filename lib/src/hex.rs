@@ -0,0 +1,290 @@
+use core::{error::Error, fmt::Display, ops::Deref};
+
+use crate::FixedStrCodec;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HexStrDecodeErr {
+    NotOfBufLen,
+    InvalidCharacter { character: char, index: usize },
+    NonAsciiCharacter { index: usize },
+}
+
+impl Display for HexStrDecodeErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotOfBufLen => f.write_str("bytes not of correct length"),
+            Self::InvalidCharacter { character, index } => write!(
+                f,
+                "provided string contained invalid character {character:?} at byte {index}"
+            ),
+            Self::NonAsciiCharacter { index } => write!(
+                f,
+                "provided string contained non-ascii character starting at byte {index}"
+            ),
+        }
+    }
+}
+
+impl Error for HexStrDecodeErr {}
+
+/// A constant-size lowercase hex-encoded string
+/// for encoding of fixed-size buffers.
+///
+/// Decoding accepts both lowercase and uppercase digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct HexString<const STR_LEN: usize>([u8; STR_LEN]);
+
+/// Constructors
+impl<const STR_LEN: usize> HexString<STR_LEN> {
+    /// Same as [`Self::decode_from_onto`], but returns an owned buffer
+    #[inline]
+    pub fn decode_from<const BUF_LEN: usize>(
+        from: &str,
+    ) -> Result<(Self, [u8; BUF_LEN]), HexStrDecodeErr> {
+        let mut buf = [0u8; BUF_LEN];
+        let res = Self::decode_from_onto(from, &mut buf)?;
+        Ok((res, buf))
+    }
+
+    /// Attempts to create [`Self`] by decoding the given str `from` onto `buf`,
+    /// verifying that it is indeed a hex-encoded buffer of len `BUF_LEN`.
+    ///
+    /// `buf` may be partially overwritten on error
+    #[inline]
+    pub fn decode_from_onto<const BUF_LEN: usize>(
+        from: &str,
+        buf: &mut [u8; BUF_LEN],
+    ) -> Result<Self, HexStrDecodeErr> {
+        const {
+            assert_hex_lens(BUF_LEN, STR_LEN);
+        }
+
+        let from: &[u8; STR_LEN] = from
+            .as_bytes()
+            .try_into()
+            .map_err(|_| HexStrDecodeErr::NotOfBufLen)?;
+        let mut res = Self(*from);
+        for (i, (b, pair)) in buf.iter_mut().zip(res.0.chunks_exact_mut(2)).enumerate() {
+            let digit = |index: usize| {
+                let c = from[index];
+                decode_digit(c).ok_or(if c.is_ascii() {
+                    HexStrDecodeErr::InvalidCharacter {
+                        character: char::from(c),
+                        index,
+                    }
+                } else {
+                    HexStrDecodeErr::NonAsciiCharacter { index }
+                })
+            };
+            let (hi, lo) = (digit(2 * i)?, digit(2 * i + 1)?);
+            *b = (hi << 4) | lo;
+            // normalize to lowercase
+            pair[0] = ALPHABET[usize::from(hi)];
+            pair[1] = ALPHABET[usize::from(lo)];
+        }
+        Ok(res)
+    }
+}
+
+/// Accessors
+impl<const STR_LEN: usize> HexString<STR_LEN> {
+    #[inline]
+    pub const fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        // safety: hex alphabet is valid ascii/utf8
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+/// Codec
+impl<const STR_LEN: usize> HexString<STR_LEN> {
+    /// Len of the encoded buffers
+    pub const BUF_LEN: usize = STR_LEN / 2;
+
+    #[inline]
+    pub fn encode<const BUF_LEN: usize>(buf: &[u8; BUF_LEN]) -> Self {
+        const {
+            assert_hex_lens(BUF_LEN, STR_LEN);
+        }
+
+        let mut res = Self([0u8; STR_LEN]);
+        for (b, pair) in buf.iter().zip(res.0.chunks_exact_mut(2)) {
+            pair[0] = ALPHABET[usize::from(b >> 4)];
+            pair[1] = ALPHABET[usize::from(b & 0xf)];
+        }
+        res
+    }
+
+    #[inline]
+    pub fn decode<const BUF_LEN: usize>(&self) -> [u8; BUF_LEN] {
+        let mut buf = [0u8; BUF_LEN];
+        self.decode_onto(&mut buf);
+        buf
+    }
+
+    /// Decodes `self` onto `buf`, overwriting previous data
+    #[inline]
+    pub fn decode_onto<const BUF_LEN: usize>(&self, buf: &mut [u8; BUF_LEN]) {
+        const {
+            assert_hex_lens(BUF_LEN, STR_LEN);
+        }
+
+        for (b, pair) in buf.iter_mut().zip(self.0.chunks_exact(2)) {
+            // safety: self is always a valid lowercase hex encoding
+            let (hi, lo) = unsafe {
+                (
+                    decode_digit(pair[0]).unwrap_unchecked(),
+                    decode_digit(pair[1]).unwrap_unchecked(),
+                )
+            };
+            *b = (hi << 4) | lo;
+        }
+    }
+}
+
+const ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+#[inline]
+const fn decode_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// The length of the hex encoding of a `BUF_LEN`-byte buffer
+pub const fn hex_str_len(buf_len: usize) -> usize {
+    buf_len * 2
+}
+
+/// Panics (at compile-time, when called in a `const {}` block)
+/// if `STR_LEN`-long hex strings do not encode `BUF_LEN`-byte buffers,
+/// i.e. if `hex_str_len(BUF_LEN) != STR_LEN`.
+#[inline]
+pub const fn assert_hex_lens(buf_len: usize, str_len: usize) {
+    assert!(
+        hex_str_len(buf_len) == str_len,
+        "hex_str_len(BUF_LEN) != STR_LEN. Use `bs58_fixed::hex_str_len(BUF_LEN)` as STR_LEN"
+    );
+}
+
+impl<const BUF_LEN: usize, const STR_LEN: usize> FixedStrCodec<BUF_LEN> for HexString<STR_LEN> {
+    type DecodeErr = HexStrDecodeErr;
+
+    const NAME: &'static str = "hex";
+
+    #[inline]
+    fn encode(buf: &[u8; BUF_LEN]) -> Self {
+        HexString::encode(buf)
+    }
+
+    #[inline]
+    fn decode_from(from: &str) -> Result<(Self, [u8; BUF_LEN]), Self::DecodeErr> {
+        HexString::decode_from(from)
+    }
+
+    #[inline]
+    fn decode(&self) -> [u8; BUF_LEN] {
+        HexString::decode(self)
+    }
+}
+
+// core traits
+
+impl<const STR_LEN: usize> Deref for HexString<STR_LEN> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const STR_LEN: usize> AsRef<str> for HexString<STR_LEN> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const STR_LEN: usize> AsRef<[u8]> for HexString<STR_LEN> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const STR_LEN: usize> Display for HexString<STR_LEN> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    macro_rules! test_suite {
+        ($BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(buf: [u8; $BUF_LEN]) {
+                    type S = HexString<{ hex_str_len($BUF_LEN) }>;
+
+                    let expected: String = buf.iter().map(|b| format!("{b:02x}")).collect();
+                    let encoded = S::encode(&buf);
+                    prop_assert_eq!(encoded.as_str(), expected.as_str());
+                    prop_assert_eq!(encoded.decode::<$BUF_LEN>(), buf);
+
+                    // uppercase is accepted but normalized
+                    let (s, decoded) = S::decode_from::<$BUF_LEN>(&expected.to_uppercase()).unwrap();
+                    prop_assert_eq!(s, encoded);
+                    prop_assert_eq!(decoded, buf);
+
+                    // wrong len
+                    if !expected.is_empty() {
+                        prop_assert_eq!(
+                            S::decode_from::<$BUF_LEN>(&expected[1..]).unwrap_err(),
+                            HexStrDecodeErr::NotOfBufLen
+                        );
+                    }
+                    prop_assert_eq!(
+                        S::decode_from::<$BUF_LEN>(&format!("{expected}00")).unwrap_err(),
+                        HexStrDecodeErr::NotOfBufLen
+                    );
+                }
+            }
+        };
+    }
+
+    test_suite!(0, b_0_test);
+    test_suite!(1, b_1_test);
+    test_suite!(16, b_16_test);
+    test_suite!(32, b_32_test);
+    test_suite!(64, b_64_test);
+
+    #[test]
+    fn invalid_char() {
+        assert_eq!(
+            HexString::<4>::decode_from::<2>("00g0").unwrap_err(),
+            HexStrDecodeErr::InvalidCharacter {
+                character: 'g',
+                index: 2
+            }
+        );
+        assert_eq!(
+            HexString::<4>::decode_from::<2>("0é0").unwrap_err(),
+            HexStrDecodeErr::NonAsciiCharacter { index: 1 }
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Line-oriented reading and writing of base58-encoded buffers,
+//! e.g. for allowlists or snapshots of keys.
+//!
+//! Both reuse a single buffer across lines so that large files are processed
+//! without per-line allocations.
+
+extern crate std;
+
+use std::{
+    io::{self, BufRead, Write},
+    vec::Vec,
+};
+
+use crate::{Bs58Str, Bs58StrDecodeErr, Bs58String};
+
+/// Iterator over the base58-encoded `[u8; BUF_LEN]`s of a [`BufRead`],
+/// one per line, or separated by [`Self::with_separator`] within lines (e.g. CSV).
+///
+/// - leading and trailing ascii whitespace of each entry (including `\r` of CRLF line endings)
+///   is ignored
+/// - empty entries and blank lines are skipped
+///
+/// Yields `Err` on io errors, and `Ok(Err((line_no, err)))` on entries that fail to decode,
+/// where `line_no` starts from 1. Iteration may continue after both,
+/// but the rest of the line is discarded on io errors.
+#[derive(Debug)]
+pub struct Bs58LineReader<R, const MAX_STR_LEN: usize, const BUF_LEN: usize> {
+    reader: R,
+    line: Vec<u8>,
+    pos: usize,
+    line_no: usize,
+    separator: Option<u8>,
+}
+
+/// Constructors
+impl<R: BufRead, const MAX_STR_LEN: usize, const BUF_LEN: usize>
+    Bs58LineReader<R, MAX_STR_LEN, BUF_LEN>
+{
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: Vec::new(),
+            pos: 0,
+            line_no: 0,
+            separator: None,
+        }
+    }
+
+    /// Additionally split lines on `separator` e.g. `b','` for CSV
+    #[inline]
+    pub fn with_separator(mut self, separator: u8) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+}
+
+/// Accessors
+impl<R, const MAX_STR_LEN: usize, const BUF_LEN: usize> Bs58LineReader<R, MAX_STR_LEN, BUF_LEN> {
+    /// 1-based number of the last line read, 0 if none have been
+    #[inline]
+    pub const fn line_no(&self) -> usize {
+        self.line_no
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead, const MAX_STR_LEN: usize, const BUF_LEN: usize> Iterator
+    for Bs58LineReader<R, MAX_STR_LEN, BUF_LEN>
+{
+    type Item = io::Result<Result<[u8; BUF_LEN], (usize, Bs58StrDecodeErr)>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.pos >= self.line.len() {
+                self.line.clear();
+                self.pos = 0;
+                match self.reader.read_until(b'\n', &mut self.line) {
+                    Ok(0) => return None,
+                    Ok(_) => self.line_no += 1,
+                    Err(e) => {
+                        // discard partially read line
+                        self.line.clear();
+                        return Some(Err(e));
+                    }
+                }
+            }
+
+            let rest = &self.line[self.pos..];
+            let entry_len = self
+                .separator
+                .and_then(|sep| rest.iter().position(|b| *b == sep))
+                .unwrap_or(rest.len());
+            // skip over the separator too
+            self.pos += entry_len + 1;
+
+            let entry = rest[..entry_len].trim_ascii();
+            if entry.is_empty() {
+                continue;
+            }
+            return Some(Ok(
+                decode_entry::<MAX_STR_LEN, BUF_LEN>(entry).map_err(|e| (self.line_no, e))
+            ));
+        }
+    }
+}
+
+#[inline]
+fn decode_entry<const MAX_STR_LEN: usize, const BUF_LEN: usize>(
+    entry: &[u8],
+) -> Result<[u8; BUF_LEN], Bs58StrDecodeErr> {
+    let s = core::str::from_utf8(entry).map_err(|e| {
+        Bs58StrDecodeErr::Bs58(bs58::decode::Error::NonAsciiCharacter {
+            index: e.valid_up_to(),
+        })
+    })?;
+    let (_, buf) = Bs58Str::<MAX_STR_LEN>::decode_from(s)?;
+    Ok(buf)
+}
+
+/// Writes base58-encoded `[u8; BUF_LEN]`s to a [`Write`], one per line.
+///
+/// Every entry is written with small `write_all()` calls, so wrap unbuffered writers
+/// such as [`std::fs::File`] in a [`std::io::BufWriter`].
+#[derive(Debug)]
+pub struct Bs58LineWriter<W, const MAX_STR_LEN: usize> {
+    writer: W,
+}
+
+/// Constructors
+impl<W: Write, const MAX_STR_LEN: usize> Bs58LineWriter<W, MAX_STR_LEN> {
+    #[inline]
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+/// Accessors
+impl<W, const MAX_STR_LEN: usize> Bs58LineWriter<W, MAX_STR_LEN> {
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Writes
+impl<W: Write, const MAX_STR_LEN: usize> Bs58LineWriter<W, MAX_STR_LEN> {
+    #[inline]
+    pub fn write_bs58_string(&mut self, s: &Bs58String<MAX_STR_LEN>) -> io::Result<()> {
+        self.writer.write_all(s.as_slice())?;
+        self.writer.write_all(b"\n")
+    }
+
+    #[inline]
+    pub fn write_buf<const BUF_LEN: usize>(&mut self, buf: &[u8; BUF_LEN]) -> io::Result<()> {
+        self.write_bs58_string(&Bs58String::encode(buf))
+    }
+
+    #[inline]
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, string::String};
+
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    type Reader<'a> = Bs58LineReader<Cursor<&'a [u8]>, 44, 32>;
+
+    fn read_all(s: &str) -> Vec<Result<[u8; 32], (usize, Bs58StrDecodeErr)>> {
+        Reader::new(Cursor::new(s.as_bytes()))
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    fn read_all_csv(s: &str) -> Vec<Result<[u8; 32], (usize, Bs58StrDecodeErr)>> {
+        Reader::new(Cursor::new(s.as_bytes()))
+            .with_separator(b',')
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(bufs in vec(any::<[u8; 32]>(), 0..32)) {
+            let mut writer = Bs58LineWriter::<_, 44>::new(Vec::new());
+            for buf in bufs.iter() {
+                writer.write_buf(buf).unwrap();
+            }
+            let written = writer.into_inner();
+
+            let expected: String = bufs.iter().map(|b| bs58::encode(b).into_string() + "\n").collect();
+            prop_assert_eq!(written.as_slice(), expected.as_bytes());
+
+            let read: Vec<_> = Reader::new(Cursor::new(written.as_slice()))
+                .map(|r| r.unwrap().unwrap())
+                .collect();
+            prop_assert_eq!(read, bufs);
+        }
+    }
+
+    #[test]
+    fn whitespace_and_blank_lines() {
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        let (sa, sb) = (bs58::encode(a).into_string(), bs58::encode(b).into_string());
+        assert_eq!(
+            read_all(&std::format!("\n  {sa}\r\n\r\n\t{sb}")),
+            [Ok(a), Ok(b)]
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        let (sa, sb) = (bs58::encode(a).into_string(), bs58::encode(b).into_string());
+        assert_eq!(
+            read_all_csv(&std::format!("{sa}, {sb},\r\n,{sb}\n")),
+            [Ok(a), Ok(b), Ok(b)]
+        );
+    }
+
+    #[test]
+    fn errors_have_line_nos() {
+        let a = [1u8; 32];
+        let sa = bs58::encode(a).into_string();
+        let res = read_all_csv(&std::format!("{sa}\n{sa},0\n\n1\n{sa}"));
+        assert_eq!(res.len(), 5);
+        assert_eq!(res[0], Ok(a));
+        assert_eq!(res[1], Ok(a));
+        assert!(matches!(res[2], Err((2, Bs58StrDecodeErr::Bs58(_)))));
+        assert_eq!(res[3], Err((4, Bs58StrDecodeErr::NotOfBufLen)));
+        assert_eq!(res[4], Ok(a));
+    }
+
+    #[test]
+    fn non_utf8() {
+        let res: Vec<_> = Reader::new(Cursor::new(b"1\xff\n".as_slice()))
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            res,
+            [Err((
+                1,
+                Bs58StrDecodeErr::Bs58(bs58::decode::Error::NonAsciiCharacter { index: 1 })
+            ))]
+        );
+    }
+}
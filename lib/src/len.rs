@@ -0,0 +1,104 @@
+use core::fmt::Display;
+
+use bs58::encode::EncodeTarget;
+
+use crate::{assert_lens, max_str_len, Bs58Str, Bs58String};
+
+/// Type-level byte length of a fixed-size buffer.
+///
+/// `Len<BUF_LEN>` implements [`Bs58Len`] for predefined sizes,
+/// which allows deriving the required base58 string capacity from `BUF_LEN` alone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Len<const BUF_LEN: usize>;
+
+/// Relates a byte buffer length to the capacity
+/// of the base58 strings that encode it
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a predefined bs58-fixed size",
+    label = "no `Bs58Len` impl for this size",
+    note = "for sizes that are not predefined, use `Bs58String<MAX_STR_LEN>` directly with `MAX_STR_LEN = bs58_fixed::max_str_len(BUF_LEN)`"
+)]
+pub trait Bs58Len {
+    const BUF_LEN: usize;
+
+    /// Always `max_str_len(Self::BUF_LEN)`
+    const MAX_STR_LEN: usize;
+
+    /// [`Bs58String`] of capacity [`Self::MAX_STR_LEN`]
+    type String: Copy + Default + EncodeTarget + AsRef<str> + Display;
+
+    /// [`Bs58Str`] of capacity [`Self::MAX_STR_LEN`]
+    type Str<'a>;
+}
+
+/// Calls the given macro with the comma-separated list
+/// of all `BUF_LEN`s that `Len<BUF_LEN>` implements [`Bs58Len`] for.
+///
+/// Used by other crates in this workspace to derive types for the same sizes.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __for_each_predefined_len {
+    ($m:ident) => {
+        $m!(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+            46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 128, 256,
+            512, 1024
+        );
+    };
+}
+
+macro_rules! impl_bs58_len {
+    ($($BUF_LEN:expr),*) => {
+        $(
+            const _: () = assert_lens($BUF_LEN, max_str_len($BUF_LEN));
+
+            impl Bs58Len for Len<$BUF_LEN> {
+                const BUF_LEN: usize = $BUF_LEN;
+                const MAX_STR_LEN: usize = max_str_len($BUF_LEN);
+                type String = Bs58String<{ max_str_len($BUF_LEN) }>;
+                type Str<'a> = Bs58Str<'a, { max_str_len($BUF_LEN) }>;
+            }
+        )*
+    };
+}
+
+__for_each_predefined_len!(impl_bs58_len);
+
+/// [`Bs58String`] for encoding `[u8; BUF_LEN]`, with capacity derived from `BUF_LEN`.
+///
+/// Only available for the sizes `Len<BUF_LEN>` implements [`Bs58Len`] for.
+pub type Bs58Fixed<const BUF_LEN: usize> = <Len<BUF_LEN> as Bs58Len>::String;
+
+/// [`Bs58Str`] of an encoded `[u8; BUF_LEN]`, with capacity derived from `BUF_LEN`.
+///
+/// Only available for the sizes `Len<BUF_LEN>` implements [`Bs58Len`] for.
+pub type Bs58FixedStr<'a, const BUF_LEN: usize> = <Len<BUF_LEN> as Bs58Len>::Str<'a>;
+
+/// Encoding of 16-byte buffers e.g. UUIDs
+pub type Bs58String16 = Bs58Fixed<16>;
+
+/// Encoding of 20-byte buffers e.g. ethereum addresses
+pub type Bs58String20 = Bs58Fixed<20>;
+
+/// Encoding of 32-byte buffers e.g. ed25519 pubkeys, hashes
+pub type Bs58String32 = Bs58Fixed<32>;
+
+/// Encoding of 64-byte buffers e.g. ed25519 signatures
+pub type Bs58String64 = Bs58Fixed<64>;
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn bs58_string_32_round_trip(buf: [u8; 32]) {
+            let encoded: Bs58String<44> = Bs58String32::encode(&buf);
+            let s: Bs58FixedStr<'_, 32> = encoded.as_bs58_str();
+            prop_assert_eq!(s.decode(), buf);
+        }
+    }
+}
@@ -1,30 +1,237 @@
 #![cfg_attr(not(test), no_std)]
 #![doc = include_str!("../README.md")]
 
+// `proptest` is also compiled for this crate's own tests
+#[cfg(any(test, feature = "alloc"))]
+extern crate alloc;
+
+mod abbrev;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+mod base64;
 mod borrowed;
+#[cfg(feature = "borsh")]
+mod borsh;
+mod codec;
+#[cfg(feature = "diesel")]
+mod diesel;
+mod display;
+#[cfg(feature = "ed25519-dalek")]
+mod ed25519_dalek;
+#[cfg(feature = "heapless")]
+mod heapless;
+mod hex;
+#[cfg(feature = "std")]
+mod io;
+mod len;
 mod owned;
+mod padded;
+mod prefix;
+#[cfg(any(test, feature = "proptest"))]
+pub mod proptest;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(any(feature = "diesel", feature = "rusqlite", feature = "sqlx"))]
+mod sql;
+#[cfg(all(test, any(feature = "diesel", feature = "rusqlite", feature = "sqlx")))]
+mod sql_tests;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "alloc")]
+mod string;
+mod var;
 
+pub use abbrev::*;
+pub use base64::*;
 pub use borrowed::*;
+pub use codec::*;
+pub use display::*;
+pub use hex::*;
+#[cfg(feature = "std")]
+pub use io::*;
+pub use len::*;
 pub use owned::*;
+pub use padded::*;
+pub use prefix::*;
+#[cfg(any(feature = "diesel", feature = "rusqlite", feature = "sqlx"))]
+pub use sql::*;
+pub use var::*;
+
+/// `log_256(58) * 2^128`, rounded down
+const LOG_256_58: u128 = 0xbb74948f5532da4b4b71433638c6ece3;
+
+/// `(log_58(256) - 1) * 2^128`, rounded down
+const LOG_58_256_FRAC: u128 = 0x5d9bc73ac2288342ba2898f943343e0a;
+
+/// `floor(n * frac / 2^128)`, where `frac` is a 128-bit fixed-point fraction.
+///
+/// The result is never greater than the true value and is less than it by at most `2^-63`
+/// before flooring.
+const fn mul_frac(n: usize, frac: u128) -> usize {
+    let n = n as u128;
+    let hi = n * (frac >> 64);
+    let lo = (n * (frac & (u64::MAX as u128))) >> 64;
+    ((hi + lo) >> 64) as usize
+}
 
 /// let `log_x()` be log base x
 ///
-/// let `lb()` be log base 2 aka `log_2()`
+/// The max number of bytes `BUF_LEN` a base58 string of length `MAX_STR_LEN` can encode:
 ///
 /// ```md
-/// 256 ^ BUF_LEN = 58 ^ MAX_STR_LEN
-/// BUF_LEN = log_256(58 ^ MAX_STR_LEN)
-///         = lb(58 ^ MAX_STR_LEN) / lb(256)
-///         = MAX_STR_LEN * lb(58) / 8
-///
-/// lb(58) = 5.857980995127572
+/// 256 ^ BUF_LEN <= 58 ^ MAX_STR_LEN
+/// BUF_LEN = floor(log_256(58 ^ MAX_STR_LEN))
+///         = floor(MAX_STR_LEN * log_256(58))
 /// ```
 ///
-/// Approximate this operation by multiplying 1000 on numerator
-/// and denominator, then round off(up) numerator
-/// so `BUF_LEN = MAX_STR_LEN * 5858 / 8000`.
+/// `log_256(58)` is irrational so the product is never an integer for `MAX_STR_LEN > 0`.
+/// It is computed with a 128-bit fixed-point `log_256(58)`, so the result is only wrong
+/// if the true product is within `2^-63` above an integer.
 ///
-/// Round down BUF_LEN to be conservative
+/// Verified against exact bignum arithmetic for all `MAX_STR_LEN <= max_str_len(4096)`.
 pub const fn buf_len(max_str_len: usize) -> usize {
-    max_str_len * 5858 / 8000
+    mul_frac(max_str_len, LOG_256_58)
+}
+
+/// Inverse of [`buf_len`]: the length of the longest base58 encoding of a
+/// `BUF_LEN`-byte buffer i.e. that of `[0xFF; BUF_LEN]`.
+///
+/// This is the smallest `MAX_STR_LEN` such that `buf_len(MAX_STR_LEN) == BUF_LEN`.
+///
+/// ```md
+/// 58 ^ MAX_STR_LEN > 256 ^ BUF_LEN - 1
+/// MAX_STR_LEN = ceil(BUF_LEN * log_58(256))
+///             = BUF_LEN + floor(BUF_LEN * (log_58(256) - 1)) + 1, for BUF_LEN > 0
+/// ```
+///
+/// Same error bound as [`buf_len`]. Verified against exact bignum arithmetic
+/// for all `BUF_LEN <= 4096`.
+pub const fn max_str_len(buf_len: usize) -> usize {
+    if buf_len == 0 {
+        0
+    } else {
+        buf_len + mul_frac(buf_len, LOG_58_256_FRAC) + 1
+    }
+}
+
+/// The length of the shortest base58 encoding of a `BUF_LEN`-byte buffer
+/// i.e. that of `[0u8; BUF_LEN]`, which is a `'1'` for every zero byte.
+pub const fn min_str_len(buf_len: usize) -> usize {
+    buf_len
+}
+
+/// Panics (at compile-time, when called in a `const {}` block)
+/// if `MAX_STR_LEN`-long base58 strings do not encode `BUF_LEN`-byte buffers,
+/// i.e. if `buf_len(MAX_STR_LEN) != BUF_LEN`.
+#[inline]
+pub const fn assert_lens(buf_len: usize, max_str_len: usize) {
+    assert!(
+        crate::buf_len(max_str_len) == buf_len,
+        "BUF_LEN != buf_len(MAX_STR_LEN). Use `bs58_fixed::max_str_len(BUF_LEN)` as MAX_STR_LEN"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use ::proptest::prelude::*;
+
+    use super::*;
+
+    const EXHAUSTIVE_MAX_BUF_LEN: usize = 4096;
+
+    /// bs58's encoding is quadratic, so only cross-check all-0xFF buffers of every len
+    /// up to this by default. Run the ignored test to cross-check every len
+    /// up to `EXHAUSTIVE_MAX_BUF_LEN`
+    const QUICK_MAX_BUF_LEN: usize = 512;
+
+    /// Step of the sparse all-0xFF cross-check up to `EXHAUSTIVE_MAX_BUF_LEN` that runs by default
+    const SPARSE_STEP: usize = 7;
+
+    /// Bit lengths of `58^s` for all `s` in `0..=max_s`, computed with exact bignum arithmetic
+    fn pow58_bit_lens(max_s: usize) -> Vec<usize> {
+        // little-endian u32 limbs
+        let mut pow = vec![1u32];
+        let mut res = Vec::with_capacity(max_s + 1);
+        for _ in 0..=max_s {
+            let top = *pow.last().unwrap();
+            res.push((pow.len() - 1) * 32 + (32 - top.leading_zeros() as usize));
+
+            let mut carry = 0u64;
+            for limb in pow.iter_mut() {
+                let prod = u64::from(*limb) * 58 + carry;
+                *limb = prod as u32;
+                carry = prod >> 32;
+            }
+            if carry > 0 {
+                pow.push(carry as u32);
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn buf_len_exhaustive() {
+        let bit_lens = pow58_bit_lens(max_str_len(EXHAUSTIVE_MAX_BUF_LEN));
+        for (s, bit_len) in bit_lens.into_iter().enumerate() {
+            // 256^b <= 58^s <=> 8b < bit_len(58^s)
+            assert_eq!(buf_len(s), (bit_len - 1) / 8, "{s}");
+        }
+    }
+
+    #[test]
+    fn max_str_len_exhaustive() {
+        let bit_lens = pow58_bit_lens(max_str_len(EXHAUSTIVE_MAX_BUF_LEN) + 1);
+        for b in 0..=EXHAUSTIVE_MAX_BUF_LEN {
+            // 58^s >= 256^b <=> bit_len(58^s) > 8b
+            let expected = bit_lens.iter().position(|bl| *bl > 8 * b).unwrap();
+            assert_eq!(max_str_len(b), expected, "{b}");
+            assert_eq!(buf_len(max_str_len(b)), b, "{b}");
+        }
+    }
+
+    fn assert_max_str_len_matches_bs58(b: usize) {
+        let max = bs58::encode(vec![0xFF; b]).into_string();
+        assert_eq!(max.len(), max_str_len(b), "{b}");
+    }
+
+    #[test]
+    fn max_str_len_matches_bs58_encodings_quick() {
+        (0..=QUICK_MAX_BUF_LEN)
+            .chain([1024, 2048, EXHAUSTIVE_MAX_BUF_LEN])
+            .for_each(assert_max_str_len_matches_bs58);
+    }
+
+    #[test]
+    fn max_str_len_matches_bs58_encodings_sparse() {
+        (QUICK_MAX_BUF_LEN + 1..=EXHAUSTIVE_MAX_BUF_LEN)
+            .step_by(SPARSE_STEP)
+            .for_each(assert_max_str_len_matches_bs58);
+    }
+
+    #[test]
+    #[ignore = "slow, quadratic bs58 encoding"]
+    fn max_str_len_matches_bs58_encodings_exhaustive() {
+        (0..=EXHAUSTIVE_MAX_BUF_LEN).for_each(assert_max_str_len_matches_bs58);
+    }
+
+    #[test]
+    fn min_str_len_matches_bs58_encodings() {
+        for b in 0..=EXHAUSTIVE_MAX_BUF_LEN {
+            let min = bs58::encode(vec![0u8; b]).into_string();
+            assert_eq!(min.len(), min_str_len(b), "{b}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn max_str_len_is_inverse_of_buf_len(b in 0usize..=1 << 48) {
+            let s = max_str_len(b);
+            prop_assert_eq!(buf_len(s), b);
+            if s > 0 {
+                prop_assert!(buf_len(s - 1) < b);
+            }
+        }
+    }
 }
@@ -2,7 +2,7 @@ use core::{cmp::Ordering, fmt::Display, ops::Deref};
 
 use bs58::encode::EncodeTarget;
 
-use crate::{buf_len, Bs58Str};
+use crate::{assert_lens, buf_len, Bs58Abbrev, Bs58Str, Bs58StrDecodeErr};
 
 /// A constant max-size base58-encoded string
 /// for encoding of fixed-size buffers
@@ -24,6 +24,19 @@ impl<const MAX_STR_LEN: usize> Bs58String<MAX_STR_LEN> {
             len: 0,
         }
     }
+
+    /// Copies the already-validated `s` into a new [`Self`]
+    #[inline]
+    pub const fn from_bs58_str(s: Bs58Str<'_, MAX_STR_LEN>) -> Self {
+        let mut res = Self::new();
+        let src = s.as_slice();
+        // safety: a valid base58-encoded buffer of BUF_LEN is at most MAX_STR_LEN long
+        unsafe {
+            core::ptr::copy_nonoverlapping(src.as_ptr(), res.buf.as_mut_ptr(), src.len());
+        }
+        res.len = src.len();
+        res
+    }
 }
 
 /// Accessors
@@ -46,6 +59,19 @@ impl<const MAX_STR_LEN: usize> Bs58String<MAX_STR_LEN> {
     }
 }
 
+/// Abbreviation
+impl<const MAX_STR_LEN: usize> Bs58String<MAX_STR_LEN> {
+    #[inline]
+    pub const fn abbrev(&self, head: usize, tail: usize) -> Bs58Abbrev<'_> {
+        Bs58Abbrev::new(self.as_str(), head, tail)
+    }
+
+    #[inline]
+    pub fn matches_abbrev(&self, abbrev: &str) -> bool {
+        self.as_bs58_str().matches_abbrev(abbrev)
+    }
+}
+
 /// Codec
 impl<const MAX_STR_LEN: usize> Bs58String<MAX_STR_LEN> {
     pub const BUF_LEN: usize = buf_len(MAX_STR_LEN);
@@ -67,7 +93,7 @@ impl<const MAX_STR_LEN: usize> Bs58String<MAX_STR_LEN> {
     #[inline]
     pub fn encode_from<const BUF_LEN: usize>(&mut self, buf: &[u8; BUF_LEN]) {
         const {
-            assert!(BUF_LEN == Self::BUF_LEN);
+            assert_lens(BUF_LEN, MAX_STR_LEN);
         }
 
         // safety: len checked at compile time above
@@ -76,6 +102,22 @@ impl<const MAX_STR_LEN: usize> Bs58String<MAX_STR_LEN> {
         }
     }
 
+    /// Same as [`Self::encode`], but for a slice whose len is only known at runtime.
+    ///
+    /// Errors with [`Bs58StrDecodeErr::NotOfBufLen`] if `buf.len() != Self::BUF_LEN`
+    #[inline]
+    pub fn try_encode_slice(buf: &[u8]) -> Result<Self, Bs58StrDecodeErr> {
+        if buf.len() != Self::BUF_LEN {
+            return Err(Bs58StrDecodeErr::NotOfBufLen);
+        }
+        let mut res = Self::new();
+        // safety: len checked above
+        unsafe {
+            bs58::encode(buf).onto(&mut res).unwrap_unchecked();
+        }
+        Ok(res)
+    }
+
     #[inline]
     pub fn decode<const BUF_LEN: usize>(&self) -> [u8; BUF_LEN] {
         self.as_bs58_str().decode()
@@ -107,6 +149,13 @@ impl<const MAX_STR_LEN: usize> EncodeTarget for Bs58String<MAX_STR_LEN> {
 
 // core traits
 
+impl<const MAX_STR_LEN: usize> From<Bs58Str<'_, MAX_STR_LEN>> for Bs58String<MAX_STR_LEN> {
+    #[inline]
+    fn from(value: Bs58Str<'_, MAX_STR_LEN>) -> Self {
+        Self::from_bs58_str(value)
+    }
+}
+
 impl<const MAX_STR_LEN: usize> Deref for Bs58String<MAX_STR_LEN> {
     type Target = str;
 
@@ -170,9 +219,10 @@ impl<const MAX_STR_LEN: usize> core::hash::Hash for Bs58String<MAX_STR_LEN> {
 }
 
 impl<const MAX_STR_LEN: usize> Display for Bs58String<MAX_STR_LEN> {
+    /// Same as [`Bs58Str`]'s [`Display`] impl
     #[inline]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str(self.as_str())
+        self.as_bs58_str().fmt(f)
     }
 }
 
@@ -197,6 +247,16 @@ mod tests {
                     // check against bs58 impl
                     let bs58_impl = bs58::encode(buf).into_string();
                     prop_assert_eq!(bs58_impl.as_str(), encoded.as_str());
+
+                    // runtime-len constructors
+                    prop_assert_eq!(S::try_encode_slice(&buf).unwrap(), encoded);
+                    prop_assert_eq!(S::from_bs58_str(encoded.as_bs58_str()), encoded);
+                    let mut longer = buf.to_vec();
+                    longer.push(0);
+                    prop_assert_eq!(
+                        S::try_encode_slice(&longer).unwrap_err(),
+                        Bs58StrDecodeErr::NotOfBufLen
+                    );
                 }
             }
         };
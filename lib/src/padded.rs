@@ -0,0 +1,273 @@
+use core::{fmt::Display, ops::Deref};
+
+use crate::{assert_lens, buf_len, display::pad, Bs58StrDecodeErr};
+
+/// A constant-size base58-encoded string for encoding of fixed-size buffers,
+/// where every buffer encodes to exactly `MAX_STR_LEN` chars.
+///
+/// The buffer is treated as a single big-endian number and its base58 digits
+/// are left-padded with the zero digit `'1'`. Since the base58 alphabet is in
+/// ascending ascii order, comparing these strings compares the buffers
+/// (lexicographically, same as `[u8; BUF_LEN]`'s [`Ord`]).
+///
+/// The padded form is the standard base58 encoding left-padded with `'1'`s,
+/// but it is NOT decodable by standard base58 decoders in general because
+/// they treat every leading `'1'` as a zero byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Bs58PaddedString<const MAX_STR_LEN: usize>([u8; MAX_STR_LEN]);
+
+/// Constructors
+impl<const MAX_STR_LEN: usize> Bs58PaddedString<MAX_STR_LEN> {
+    /// Same as [`Self::decode_from_onto`], but returns an owned buffer
+    #[inline]
+    pub fn decode_from<const BUF_LEN: usize>(
+        from: &str,
+    ) -> Result<(Self, [u8; BUF_LEN]), Bs58StrDecodeErr> {
+        let mut buf = [0u8; BUF_LEN];
+        let res = Self::decode_from_onto(from, &mut buf)?;
+        Ok((res, buf))
+    }
+
+    // Need to use a const generic with comptime assertion
+    // here instead of associated const
+    // because we cant do `-> [u8; Self::BUF_LEN]` yet
+    //
+    /// Attempts to create [`Self`] by decoding the given str `from` onto `buf`.
+    ///
+    /// `from` can be either the padded or the standard base58 encoding of
+    /// a `BUF_LEN`-byte buffer, or anything in between, as long as it is at most
+    /// `MAX_STR_LEN` long. Leading `'1'`s are zero digits, not zero bytes.
+    #[inline]
+    pub fn decode_from_onto<const BUF_LEN: usize>(
+        from: &str,
+        buf: &mut [u8; BUF_LEN],
+    ) -> Result<Self, Bs58StrDecodeErr> {
+        const {
+            assert_lens(BUF_LEN, MAX_STR_LEN);
+        }
+
+        if from.len() > MAX_STR_LEN {
+            return Err(Bs58StrDecodeErr::NotOfBufLen);
+        }
+        decode_digits(from.as_bytes(), buf)?;
+
+        // left-padding any accepted form with '1's results in the padded form
+        let mut res = [b'1'; MAX_STR_LEN];
+        res[MAX_STR_LEN - from.len()..].copy_from_slice(from.as_bytes());
+        Ok(Self(res))
+    }
+}
+
+/// Accessors
+impl<const MAX_STR_LEN: usize> Bs58PaddedString<MAX_STR_LEN> {
+    #[inline]
+    pub const fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        // safety: bs58 alphabet is valid ascii/utf8
+        unsafe { core::str::from_utf8_unchecked(self.as_slice()) }
+    }
+}
+
+/// Codec
+impl<const MAX_STR_LEN: usize> Bs58PaddedString<MAX_STR_LEN> {
+    pub const BUF_LEN: usize = buf_len(MAX_STR_LEN);
+
+    // Need to use a const generic with comptime assertion
+    // here instead of associated const
+    // because we cant do `buf: &[u8; Self::BUF_LEN]` yet
+    #[inline]
+    pub fn encode<const BUF_LEN: usize>(buf: &[u8; BUF_LEN]) -> Self {
+        let mut res = Self([b'1'; MAX_STR_LEN]);
+        res.encode_from(buf);
+        res
+    }
+
+    // Need to use a const generic with comptime assertion
+    // here instead of associated const
+    // because we cant do `buf: &[u8; Self::BUF_LEN]` yet
+    /// Encodes `buf` onto `self`, overwriting previous data
+    #[inline]
+    pub fn encode_from<const BUF_LEN: usize>(&mut self, buf: &[u8; BUF_LEN]) {
+        const {
+            assert_lens(BUF_LEN, MAX_STR_LEN);
+        }
+
+        // safety: len checked at compile time above
+        let len = unsafe {
+            bs58::encode(buf)
+                .onto(self.0.as_mut_slice())
+                .unwrap_unchecked()
+        };
+        // standard encoding left-padded with '1's is the padded encoding
+        self.0.copy_within(..len, MAX_STR_LEN - len);
+        self.0[..MAX_STR_LEN - len].fill(b'1');
+    }
+
+    #[inline]
+    pub fn decode<const BUF_LEN: usize>(&self) -> [u8; BUF_LEN] {
+        let mut res = [0u8; BUF_LEN];
+        self.decode_onto(&mut res);
+        res
+    }
+
+    /// Decodes `self` onto `buf`, overwriting previous data
+    #[inline]
+    pub fn decode_onto<const BUF_LEN: usize>(&self, buf: &mut [u8; BUF_LEN]) {
+        const {
+            assert_lens(BUF_LEN, MAX_STR_LEN);
+        }
+
+        // safety: struct guaranteed to be a valid padded encoding of
+        // a buffer of the correct length at construction time
+        unsafe { decode_digits(&self.0, buf).unwrap_unchecked() }
+    }
+}
+
+/// Decodes `digits` as a single big-endian number onto `buf`,
+/// i.e. leading `'1'`s do not correspond to leading zero bytes
+#[inline]
+fn decode_digits<const BUF_LEN: usize>(
+    digits: &[u8],
+    buf: &mut [u8; BUF_LEN],
+) -> Result<(), Bs58StrDecodeErr> {
+    let ones = digits.iter().take_while(|c| **c == b'1').count();
+    // no leading '1's left, so result has no leading zero bytes
+    let len = bs58::decode(&digits[ones..])
+        .onto(buf.as_mut_slice())
+        .map_err(|e| match e {
+            bs58::decode::Error::BufferTooSmall => Bs58StrDecodeErr::NotOfBufLen,
+            bs58::decode::Error::InvalidCharacter { character, index } => {
+                Bs58StrDecodeErr::Bs58(bs58::decode::Error::InvalidCharacter {
+                    character,
+                    index: index + ones,
+                })
+            }
+            bs58::decode::Error::NonAsciiCharacter { index } => {
+                Bs58StrDecodeErr::Bs58(bs58::decode::Error::NonAsciiCharacter {
+                    index: index + ones,
+                })
+            }
+            e => Bs58StrDecodeErr::Bs58(e),
+        })?;
+    buf.copy_within(..len, BUF_LEN - len);
+    buf[..BUF_LEN - len].fill(0);
+    Ok(())
+}
+
+// core traits
+
+impl<const MAX_STR_LEN: usize> Deref for Bs58PaddedString<MAX_STR_LEN> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const MAX_STR_LEN: usize> AsRef<str> for Bs58PaddedString<MAX_STR_LEN> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const MAX_STR_LEN: usize> AsRef<[u8]> for Bs58PaddedString<MAX_STR_LEN> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const MAX_STR_LEN: usize> Display for Bs58PaddedString<MAX_STR_LEN> {
+    /// Respects width, fill and alignment.
+    /// Precision is ignored, so the encoding is never truncated
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = self.as_str();
+        pad(f, s.len(), |f| f.write_str(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    macro_rules! test_suite {
+        ($MAX_STR_LEN:expr, $BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(a: [u8; $BUF_LEN], b: [u8; $BUF_LEN]) {
+                    type S = Bs58PaddedString<$MAX_STR_LEN>;
+
+                    let ea = S::encode(&a);
+                    let eb = S::encode(&b);
+
+                    // fixed-width
+                    prop_assert_eq!(ea.len(), $MAX_STR_LEN);
+
+                    // round-trip
+                    prop_assert_eq!(ea.decode(), a);
+                    let (d, buf) = S::decode_from(ea.as_str()).unwrap();
+                    prop_assert_eq!(d, ea);
+                    prop_assert_eq!(buf, a);
+
+                    // order-preserving
+                    prop_assert_eq!(ea.cmp(&eb), a.cmp(&b));
+                    prop_assert_eq!(ea.as_str().cmp(eb.as_str()), a.cmp(&b));
+
+                    // standard form is accepted and padded
+                    let standard = bs58::encode(a).into_string();
+                    prop_assert_eq!(ea.trim_start_matches('1'), standard.trim_start_matches('1'));
+                    let (d, buf) = S::decode_from(&standard).unwrap();
+                    prop_assert_eq!(d, ea);
+                    prop_assert_eq!(buf, a);
+                }
+            }
+        };
+    }
+
+    test_suite!(0, 0, b_0_0_test);
+    test_suite!(1, 0, b_1_0_test);
+    test_suite!(2, 1, b_2_1_test);
+    test_suite!(3, 2, b_3_2_test);
+    test_suite!(4, 2, b_4_2_test);
+    test_suite!(11, 8, b_11_8_test);
+    test_suite!(22, 16, b_22_16_test);
+    test_suite!(44, 32, b_44_32_test);
+    test_suite!(88, 64, b_88_64_test);
+
+    #[test]
+    fn decode_from_errs() {
+        type S = Bs58PaddedString<44>;
+
+        // > 2^256
+        assert_eq!(
+            S::decode_from::<32>(&"z".repeat(44)).unwrap_err(),
+            Bs58StrDecodeErr::NotOfBufLen
+        );
+        // too long
+        assert_eq!(
+            S::decode_from::<32>(&"1".repeat(45)).unwrap_err(),
+            Bs58StrDecodeErr::NotOfBufLen
+        );
+        assert_eq!(
+            S::decode_from::<32>("11l").unwrap_err(),
+            Bs58StrDecodeErr::Bs58(bs58::decode::Error::InvalidCharacter {
+                character: 'l',
+                index: 2
+            })
+        );
+
+        let (s, buf) = S::decode_from::<32>("").unwrap();
+        assert_eq!(buf, [0u8; 32]);
+        assert_eq!(s.as_str(), "1".repeat(44));
+    }
+}
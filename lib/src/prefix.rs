@@ -0,0 +1,317 @@
+use core::ops::RangeInclusive;
+
+use crate::max_str_len;
+
+/// The default (bitcoin) base58 alphabet, in ascending order of digit value.
+///
+/// Also in ascending ascii order, so comparing equal-length base58 strings
+/// compares the numbers they encode.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Returns the value of the base58 digit `c`, or `None` if `c` is not in the alphabet
+#[inline]
+const fn digit(c: u8) -> Option<u8> {
+    let mut i = 0;
+    while i < ALPHABET.len() {
+        if ALPHABET[i] == c {
+            return Some(i as u8);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Byte ranges, in ascending order, of all `[u8; BUF_LEN]`s
+/// whose base58 encodings start with a given prefix.
+///
+/// Base58 prefixes do not map to byte prefixes, but they do map to contiguous
+/// ranges of big-endian byte buffers (which are ordered the same way as the
+/// buffers themselves), at most one per possible encoded length.
+///
+/// Use [`Self::ranges`] for range queries over sorted keys
+/// and [`Self::matches`] to check if a buffer's encoding starts with the prefix
+/// without encoding it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bs58PrefixMatcher<const BUF_LEN: usize> {
+    // At most 2 ranges: only the leading-'1's-only prefix has a single range,
+    // otherwise the rest of the prefix encodes some p >= 1 and values are in
+    // [min, max] with max < 256 * min. The range for `e` extra digits is
+    // [p * 58^e, (p + 1) * 58^e), so if the ranges for `e` and `e + 2` both intersected
+    // [min, max], then p * 58^(e + 2) <= max < 256 * min < 256 * (p + 1) * 58^e
+    // i.e. 3364 * p < 256 * (p + 1), which is false for all p >= 1
+    ranges: [RangeInclusive<[u8; BUF_LEN]>; 2],
+    len: usize,
+}
+
+/// Constructors
+impl<const BUF_LEN: usize> Bs58PrefixMatcher<BUF_LEN> {
+    /// Returns an error if `prefix` contains chars not in the base58 alphabet
+    pub fn new(prefix: &str) -> Result<Self, bs58::decode::Error> {
+        let mut res = Self {
+            ranges: [
+                [0u8; BUF_LEN]..=[0u8; BUF_LEN],
+                [0u8; BUF_LEN]..=[0u8; BUF_LEN],
+            ],
+            len: 0,
+        };
+
+        // every leading '1' is a leading zero byte
+        let ones = prefix.bytes().take_while(|c| *c == ALPHABET[0]).count();
+        let rest = &prefix[ones..];
+        let mut rest_val = [0u8; BUF_LEN];
+        let mut rest_overflow = false;
+        for (i, c) in rest.bytes().enumerate() {
+            let Some(d) = digit(c) else {
+                let index = ones + i;
+                return Err(if c.is_ascii() {
+                    bs58::decode::Error::InvalidCharacter {
+                        character: char::from(c),
+                        index,
+                    }
+                } else {
+                    bs58::decode::Error::NonAsciiCharacter { index }
+                });
+            };
+            rest_overflow |= mul_add(&mut rest_val, 58, d);
+        }
+
+        if ones > BUF_LEN || rest_overflow {
+            return Ok(res);
+        }
+
+        // max value with at least `ones` leading zero bytes
+        let mut max = [0xFF; BUF_LEN];
+        max[..ones].fill(0);
+
+        if rest.is_empty() {
+            res.push([0u8; BUF_LEN]..=max);
+            return Ok(res);
+        }
+
+        // rest of the prefix starts with a nonzero digit,
+        // so there must be exactly `ones` leading zero bytes
+        if ones == BUF_LEN {
+            return Ok(res);
+        }
+        let mut min = [0u8; BUF_LEN];
+        min[ones] = 1;
+
+        let max_pow = BUF_LEN - ones;
+        let min_digits = max_str_len(max_pow - 1).max(1).max(rest.len());
+        for digits in min_digits..=max_str_len(max_pow) {
+            // [rest_val * 58^e, (rest_val + 1) * 58^e - 1]
+            let e = digits - rest.len();
+            let mut start = rest_val;
+            let mut end = rest_val;
+            let mut end_overflow = add_one(&mut end);
+            let mut start_overflow = false;
+            for _ in 0..e {
+                start_overflow |= mul_add(&mut start, 58, 0);
+                end_overflow |= mul_add(&mut end, 58, 0);
+            }
+            if start_overflow {
+                break;
+            }
+            if end_overflow {
+                end = [0xFF; BUF_LEN];
+            } else {
+                sub_one(&mut end);
+            }
+
+            let start = start.max(min);
+            let end = end.min(max);
+            if start <= end {
+                res.push(start..=end);
+            }
+        }
+
+        Ok(res)
+    }
+
+    #[inline]
+    fn push(&mut self, range: RangeInclusive<[u8; BUF_LEN]>) {
+        debug_assert!(self.len < self.ranges.len(), "more than 2 prefix ranges");
+        self.ranges[self.len] = range;
+        self.len += 1;
+    }
+}
+
+/// Accessors
+impl<const BUF_LEN: usize> Bs58PrefixMatcher<BUF_LEN> {
+    /// Disjoint, non-empty, inclusive ranges in ascending order.
+    ///
+    /// Empty if no `BUF_LEN`-byte buffer's encoding starts with the prefix
+    #[inline]
+    pub fn ranges(&self) -> &[RangeInclusive<[u8; BUF_LEN]>] {
+        &self.ranges[..self.len]
+    }
+
+    /// Returns true if the base58 encoding of `buf` starts with the prefix
+    #[inline]
+    pub fn matches(&self, buf: &[u8; BUF_LEN]) -> bool {
+        self.ranges().iter().any(|r| r.contains(buf))
+    }
+}
+
+/// Same as [`Bs58PrefixMatcher::matches`]
+#[inline]
+pub fn starts_with_prefix<const BUF_LEN: usize>(
+    buf: &[u8; BUF_LEN],
+    matcher: &Bs58PrefixMatcher<BUF_LEN>,
+) -> bool {
+    matcher.matches(buf)
+}
+
+// big-endian fixed-size bignum arithmetic
+
+/// `n = n * mul + add`, returns true if overflowed
+#[inline]
+fn mul_add<const N: usize>(n: &mut [u8; N], mul: u8, add: u8) -> bool {
+    let mut carry = u16::from(add);
+    n.iter_mut().rev().for_each(|b| {
+        let x = u16::from(*b) * u16::from(mul) + carry;
+        *b = x as u8;
+        carry = x >> 8;
+    });
+    carry > 0
+}
+
+/// `n = n + 1`, returns true if overflowed
+#[inline]
+fn add_one<const N: usize>(n: &mut [u8; N]) -> bool {
+    for b in n.iter_mut().rev() {
+        let (x, overflow) = b.overflowing_add(1);
+        *b = x;
+        if !overflow {
+            return false;
+        }
+    }
+    true
+}
+
+/// `n = n - 1`. `n` must be nonzero
+#[inline]
+fn sub_one<const N: usize>(n: &mut [u8; N]) {
+    for b in n.iter_mut().rev() {
+        let (x, underflow) = b.overflowing_sub(1);
+        *b = x;
+        if !underflow {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    fn check_matcher<const BUF_LEN: usize>(prefix: &str, buf: &[u8; BUF_LEN]) {
+        let m = Bs58PrefixMatcher::<BUF_LEN>::new(prefix).unwrap();
+        let expected = bs58::encode(buf).into_string().starts_with(prefix);
+        assert_eq!(m.matches(buf), expected, "{prefix} {buf:?}");
+        assert_eq!(starts_with_prefix(buf, &m), expected);
+    }
+
+    fn check_ranges_tight<const BUF_LEN: usize>(prefix: &str) {
+        let m = Bs58PrefixMatcher::<BUF_LEN>::new(prefix).unwrap();
+        let matches = |buf: &[u8; BUF_LEN]| bs58::encode(buf).into_string().starts_with(prefix);
+        m.ranges()
+            .windows(2)
+            .for_each(|w| assert!(w[0].end() < w[1].start()));
+        for r in m.ranges() {
+            assert!(matches(r.start()), "{prefix} {r:?}");
+            assert!(matches(r.end()), "{prefix} {r:?}");
+
+            let mut before = *r.start();
+            if before != [0u8; BUF_LEN] {
+                sub_one(&mut before);
+                assert!(!matches(&before), "{prefix} {r:?}");
+            }
+            let mut after = *r.end();
+            if !add_one(&mut after) {
+                assert!(!matches(&after), "{prefix} {r:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn one_byte_exhaustive() {
+        let prefixes = core::iter::once(String::new())
+            .chain(ALPHABET.iter().map(|a| char::from(*a).to_string()))
+            .chain(ALPHABET.iter().flat_map(|a| {
+                ALPHABET
+                    .iter()
+                    .map(|b| format!("{}{}", *a as char, *b as char))
+            }));
+        for prefix in prefixes {
+            check_ranges_tight::<1>(&prefix);
+            for b in 0..=u8::MAX {
+                check_matcher(&prefix, &[b]);
+            }
+        }
+    }
+
+    #[test]
+    fn known_ranges() {
+        let m = Bs58PrefixMatcher::<2>::new("").unwrap();
+        assert_eq!(m.ranges(), &[[0, 0]..=[0xFF, 0xFF]]);
+
+        let m = Bs58PrefixMatcher::<2>::new("11").unwrap();
+        assert_eq!(m.ranges(), &[[0, 0]..=[0, 0]]);
+
+        let m = Bs58PrefixMatcher::<2>::new("111").unwrap();
+        assert!(m.ranges().is_empty());
+
+        let m = Bs58PrefixMatcher::<2>::new("zzzz").unwrap();
+        assert!(m.ranges().is_empty());
+
+        let m = Bs58PrefixMatcher::<32>::new("Stake").unwrap();
+        assert!(!m.ranges().is_empty());
+        for r in m.ranges() {
+            assert!(bs58::encode(r.start()).into_string().starts_with("Stake"));
+            assert!(bs58::encode(r.end()).into_string().starts_with("Stake"));
+        }
+
+        assert_eq!(
+            Bs58PrefixMatcher::<32>::new("Stake0").unwrap_err(),
+            bs58::decode::Error::InvalidCharacter {
+                character: '0',
+                index: 5
+            }
+        );
+    }
+
+    fn prefix_strat(max_len: usize) -> impl Strategy<Value = String> {
+        vec(0..ALPHABET.len(), 0..=max_len)
+            .prop_map(|v| v.into_iter().map(|i| char::from(ALPHABET[i])).collect())
+    }
+
+    proptest! {
+        #[test]
+        fn two_bytes(buf: [u8; 2], prefix in prefix_strat(4)) {
+            check_matcher(&prefix, &buf);
+            check_ranges_tight::<2>(&prefix);
+        }
+
+        #[test]
+        fn pubkey_random_prefix(buf: [u8; 32], prefix in prefix_strat(6)) {
+            check_matcher(&prefix, &buf);
+            check_ranges_tight::<32>(&prefix);
+        }
+
+        #[test]
+        fn pubkey_own_prefix(
+            mut buf: [u8; 32],
+            zeros in 0usize..=32,
+            prefix_len in 0usize..=44,
+        ) {
+            buf[..zeros].fill(0);
+            let s = bs58::encode(buf).into_string();
+            let prefix = &s[..prefix_len.min(s.len())];
+            check_matcher(prefix, &buf);
+            check_ranges_tight::<32>(prefix);
+        }
+    }
+}
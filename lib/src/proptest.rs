@@ -0,0 +1,164 @@
+//! [`proptest`](https://docs.rs/proptest) strategies for fuzzing base58 codecs.
+//!
+//! [`Bs58String`] also implements [`Arbitrary`], so `any::<Bs58String<MAX_STR_LEN>>()`
+//! is the same as [`bs58_string`].
+
+use alloc::{string::String, vec::Vec};
+
+use proptest::{
+    arbitrary::Arbitrary,
+    collection::vec,
+    prelude::*,
+    sample::{select, Index},
+};
+
+use crate::Bs58String;
+
+/// Known `(decoded, encoded)` pairs
+pub const TEST_VECTORS: &[(&[u8], &str)] = &[
+    (&[], ""),
+    (&[0x00], "1"),
+    (&[0x00, 0x00, 0x00], "111"),
+    (&[0x61], "2g"),
+    (&[0xff], "5Q"),
+    (b"Hello World!", "2NEpo7TZRRrLZSi2U"),
+    (&[0x00, 0x00, 0x28, 0x7f, 0xb4, 0xcd], "11233QC4"),
+    (&[0x00; 16], "1111111111111111"),
+    (&[0xff; 16], "YcVfxkQb6JRzqk5kF2tNLv"),
+    (&[0x00; 20], "11111111111111111111"),
+    (&[0xff; 20], "4ZrjxJnU1LA5xSyrWMNuXTvSYKwt"),
+    (&[0x00; 32], "11111111111111111111111111111111"),
+    (
+        &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x01,
+        ],
+        "11111111111111111111111111111112",
+    ),
+    (&[0xff; 32], "JEKNVnkbo3jma5nREBBJCDoXFVeKkD56V3xKrvRmWxFG"),
+    (
+        &[0x00; 64],
+        "1111111111111111111111111111111111111111111111111111111111111111",
+    ),
+    (
+        &[0xff; 64],
+        "67rpwLCuS5DGA8KGZXKsVQ7dnPb9goRLoKfgGbLfQg9WoLUgNY77E2jT11fem3coV9nAkguBACzrU1iyZM4B8roQ",
+    ),
+];
+
+/// Chars that are commonly confused with, or look like they could be in, the base58 alphabet
+const NOT_IN_ALPHABET: &[char] = &['0', 'O', 'I', 'l', '+', '/', '=', ' ', '\0', 'é'];
+
+/// Byte buffers of `buf_len`, biased towards edge cases:
+/// - leading zero bytes, which encode to leading '1's
+/// - all 0xFF, which encode to the longest string
+pub fn buf(buf_len: usize) -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        vec(any::<u8>(), buf_len),
+        (0..=buf_len, vec(any::<u8>(), buf_len)).prop_map(|(zeros, mut v)| {
+            v[..zeros].fill(0);
+            v
+        }),
+        Just(alloc::vec![0xff; buf_len]),
+    ]
+}
+
+/// Valid [`Bs58String`]s, with the same biases as [`buf`]
+pub fn bs58_string<const MAX_STR_LEN: usize>() -> impl Strategy<Value = Bs58String<MAX_STR_LEN>> {
+    buf(Bs58String::<MAX_STR_LEN>::BUF_LEN).prop_map(|v| {
+        // safety: v is of len BUF_LEN
+        unsafe { Bs58String::try_encode_slice(&v).unwrap_unchecked() }
+    })
+}
+
+/// Strings that are close to, but are not, valid base58 encodings of a `[u8; BUF_LEN]`:
+/// - a valid encoding with a single char replaced by one outside the base58 alphabet
+/// - encodings of buffers of len `BUF_LEN - 1` or `BUF_LEN + 1`, which include
+///   valid encodings with a leading '1' added or removed
+pub fn near_miss(buf_len: usize) -> impl Strategy<Value = String> {
+    let bad_char = (buf(buf_len), any::<Index>(), select(NOT_IN_ALPHABET)).prop_map(|(v, i, c)| {
+        let mut s = bs58::encode(v).into_string();
+        if s.is_empty() {
+            s.push(c);
+        } else {
+            // base58 alphabet is ascii, so every byte index is a char boundary
+            let i = i.index(s.len());
+            s.replace_range(i..=i, c.encode_utf8(&mut [0u8; 4]));
+        }
+        s
+    });
+    let off_by_one_lens: Vec<usize> = [buf_len.checked_sub(1), Some(buf_len + 1)]
+        .into_iter()
+        .flatten()
+        .collect();
+    let off_by_one = select(off_by_one_lens)
+        .prop_flat_map(buf)
+        .prop_map(|v| bs58::encode(v).into_string());
+    prop_oneof![bad_char, off_by_one]
+}
+
+/// [`TEST_VECTORS`] of decoded len `buf_len`.
+///
+/// # Panics
+/// If there are no test vectors of decoded len `buf_len`
+pub fn test_vector(buf_len: usize) -> impl Strategy<Value = (&'static [u8], &'static str)> {
+    let vectors: Vec<_> = TEST_VECTORS
+        .iter()
+        .copied()
+        .filter(|(decoded, _)| decoded.len() == buf_len)
+        .collect();
+    select(vectors)
+}
+
+impl<const MAX_STR_LEN: usize> Arbitrary for Bs58String<MAX_STR_LEN> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    #[inline]
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        bs58_string().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bs58Str;
+
+    #[test]
+    fn test_vectors_match_bs58() {
+        for (decoded, encoded) in TEST_VECTORS {
+            assert_eq!(bs58::encode(decoded).into_string(), *encoded);
+        }
+    }
+
+    macro_rules! test_suite {
+        ($MAX_STR_LEN:expr, $BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(
+                    s in bs58_string::<$MAX_STR_LEN>(),
+                    miss in near_miss($BUF_LEN),
+                    (decoded, encoded) in test_vector($BUF_LEN),
+                ) {
+                    type S<'a> = Bs58Str<'a, $MAX_STR_LEN>;
+
+                    prop_assert_eq!(S::try_new(s.as_str()).unwrap(), s.as_bs58_str());
+                    prop_assert!(S::try_new(&miss).is_err());
+
+                    let (s, buf) = S::decode_from::<$BUF_LEN>(encoded).unwrap();
+                    prop_assert_eq!(s.as_str(), encoded);
+                    prop_assert_eq!(buf.as_slice(), decoded);
+                }
+            }
+        };
+    }
+
+    test_suite!(1, 0, b_1_0_test);
+    test_suite!(2, 1, b_2_1_test);
+    test_suite!(22, 16, b_22_16_test);
+    test_suite!(28, 20, b_28_20_test);
+    test_suite!(44, 32, b_44_32_test);
+    test_suite!(88, 64, b_88_64_test);
+}
@@ -0,0 +1,64 @@
+//! [`Bs58String`] is written as a TEXT base58 string,
+//! or as a BLOB of the decoded `[u8; BUF_LEN]` through [`Bs58String::to_blob`].
+//!
+//! It can be read from either a TEXT column containing the base58 string
+//! or a BLOB column containing the decoded `[u8; BUF_LEN]`.
+
+use rusqlite::{
+    types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef},
+    Result, ToSql,
+};
+
+use crate::{Bs58Blob, Bs58Str, Bs58String};
+
+impl<const MAX_STR_LEN: usize> ToSql for Bs58String<MAX_STR_LEN> {
+    #[inline]
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Text(self.as_slice())))
+    }
+}
+
+impl<const MAX_STR_LEN: usize> ToSql for Bs58Str<'_, MAX_STR_LEN> {
+    #[inline]
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Text(self.as_slice())))
+    }
+}
+
+impl<const BUF_LEN: usize> ToSql for Bs58Blob<BUF_LEN> {
+    #[inline]
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Blob(self.as_slice())))
+    }
+}
+
+impl<const MAX_STR_LEN: usize> FromSql for Bs58String<MAX_STR_LEN> {
+    #[inline]
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(_) => Bs58Str::try_new(value.as_str()?)
+                .map(Self::from_bs58_str)
+                .map_err(FromSqlError::other),
+            ValueRef::Blob(b) => {
+                Self::try_encode_slice(b).map_err(|_| FromSqlError::InvalidBlobSize {
+                    expected_size: Self::BUF_LEN,
+                    blob_size: b.len(),
+                })
+            }
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sql_tests::test_suite! {
+        rusqlite,
+        Bs58String<MAX_STR_LEN>,
+        new: |buf| Bs58String::encode(&buf),
+        text: |s| *s,
+        blob: |s| s.to_blob::<BUF_LEN>(),
+    }
+}
@@ -0,0 +1,27 @@
+//! Common definitions for sql database features
+
+use crate::Bs58String;
+
+/// Writes a [`Bs58String`]'s decoded `[u8; BUF_LEN]` as a BLOB
+/// instead of the TEXT base58 string [`Bs58String`] itself is written as.
+///
+/// Created with [`Bs58String::to_blob`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Bs58Blob<const BUF_LEN: usize>(pub [u8; BUF_LEN]);
+
+/// Sql
+impl<const MAX_STR_LEN: usize> Bs58String<MAX_STR_LEN> {
+    /// Decodes `self` to be written as a BLOB
+    #[inline]
+    pub fn to_blob<const BUF_LEN: usize>(&self) -> Bs58Blob<BUF_LEN> {
+        Bs58Blob(self.decode())
+    }
+}
+
+impl<const BUF_LEN: usize> Bs58Blob<BUF_LEN> {
+    #[inline]
+    pub const fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+}
@@ -0,0 +1,255 @@
+//! Test suite shared by the sql database features of both `bs58-fixed` and `bs58-fixed-wasm`,
+//! which `#[path]`-includes this file.
+//!
+//! Each test writes a value through both its TEXT and its BLOB mapping
+//! into an in-memory sqlite `t (txt TEXT, blb BLOB)` table and reads it back from both columns.
+
+/// ```ignore
+/// test_suite! {
+///     rusqlite, // or diesel, sqlx
+///     // type under test, may use `BUF_LEN` and `MAX_STR_LEN`
+///     Bs58String<MAX_STR_LEN>,
+///     // value under test from `buf: [u8; BUF_LEN]`
+///     new: |buf| Bs58String::<MAX_STR_LEN>::encode(&buf),
+///     // TEXT and BLOB mappings of a `&` value under test
+///     text: |s| *s,
+///     blob: |s| s.to_blob::<BUF_LEN>(),
+/// }
+/// ```
+macro_rules! test_suite {
+    (
+        $backend:ident,
+        $T:ty,
+        new: |$buf:ident| $new:expr,
+        text: |$t:ident| $text:expr,
+        blob: |$b:ident| $blob:expr $(,)?
+    ) => {
+        $crate::sql_tests::test_suite!(@len b_0_1, 0, 1, $backend, $T, $buf, $new, $t, $text, $b, $blob);
+        $crate::sql_tests::test_suite!(@len b_2_4, 2, 4, $backend, $T, $buf, $new, $t, $text, $b, $blob);
+        $crate::sql_tests::test_suite!(@len b_32_44, 32, 44, $backend, $T, $buf, $new, $t, $text, $b, $blob);
+        $crate::sql_tests::test_suite!(@len b_64_88, 64, 88, $backend, $T, $buf, $new, $t, $text, $b, $blob);
+    };
+
+    (
+        @len $mod:ident, $BUF_LEN:literal, $MAX_STR_LEN:literal,
+        $backend:ident, $T:ty, $buf:ident, $new:expr, $t:ident, $text:expr, $b:ident, $blob:expr
+    ) => {
+        mod $mod {
+            use ::proptest::prelude::*;
+
+            use super::*;
+
+            const BUF_LEN: usize = $BUF_LEN;
+            #[allow(dead_code)]
+            const MAX_STR_LEN: usize = $MAX_STR_LEN;
+
+            type T = $T;
+
+            fn new($buf: [u8; BUF_LEN]) -> T {
+                $new
+            }
+
+            $crate::sql_tests::test_suite!(@$backend $t, $text, $b, $blob);
+        }
+    };
+
+    (@rusqlite $t:ident, $text:expr, $b:ident, $blob:expr) => {
+        use ::rusqlite::Connection;
+
+        fn conn() -> Connection {
+            let conn = Connection::open_in_memory().unwrap();
+            conn.execute_batch("CREATE TABLE t (txt TEXT, blb BLOB)")
+                .unwrap();
+            conn
+        }
+
+        proptest! {
+            #[test]
+            fn round_trip(buf: [u8; BUF_LEN]) {
+                let conn = conn();
+                let expected = new(buf);
+                let (txt, blb) = ({ let $t = &expected; $text }, { let $b = &expected; $blob });
+                conn.execute("INSERT INTO t (txt, blb) VALUES (?1, ?2)", (txt, blb)).unwrap();
+
+                // written as TEXT and BLOB
+                let (txt, blb): (String, Vec<u8>) = conn
+                    .query_row(
+                        "SELECT txt, blb FROM t WHERE typeof(txt) = 'text' AND typeof(blb) = 'blob'",
+                        [],
+                        |r| Ok((r.get(0)?, r.get(1)?)),
+                    )
+                    .unwrap();
+                prop_assert_eq!(txt, ::bs58::encode(buf).into_string());
+                prop_assert_eq!(blb.as_slice(), buf.as_slice());
+
+                // read from TEXT and BLOB
+                let (from_txt, from_blb): (T, T) = conn
+                    .query_row("SELECT txt, blb FROM t", [], |r| Ok((r.get(0)?, r.get(1)?)))
+                    .unwrap();
+                prop_assert_eq!(from_txt, expected);
+                prop_assert_eq!(from_blb, expected);
+            }
+        }
+
+        #[test]
+        fn invalid_rejected() {
+            let conn = conn();
+            conn.execute(
+                "INSERT INTO t (txt, blb) VALUES (?1, ?2)",
+                ("0", [0u8; BUF_LEN + 1].as_slice()),
+            )
+            .unwrap();
+            conn.query_row("SELECT txt FROM t", [], |r| r.get::<_, T>(0))
+                .unwrap_err();
+            conn.query_row("SELECT blb FROM t", [], |r| r.get::<_, T>(0))
+                .unwrap_err();
+        }
+    };
+
+    (@diesel $t:ident, $text:expr, $b:ident, $blob:expr) => {
+        use ::diesel::{dsl::sql, prelude::*, sql_query, sql_types::Bool, sqlite::SqliteConnection};
+
+        ::diesel::table! {
+            t (id) {
+                id -> Integer,
+                txt -> Text,
+                blb -> Binary,
+            }
+        }
+
+        fn conn() -> SqliteConnection {
+            let mut conn = SqliteConnection::establish(":memory:").unwrap();
+            sql_query("CREATE TABLE t (id INTEGER PRIMARY KEY, txt TEXT NOT NULL, blb BLOB NOT NULL)")
+                .execute(&mut conn)
+                .unwrap();
+            conn
+        }
+
+        proptest! {
+            #[test]
+            fn round_trip(buf: [u8; BUF_LEN]) {
+                let mut conn = conn();
+                let expected = new(buf);
+                let (txt, blb) = ({ let $t = &expected; $text }, { let $b = &expected; $blob });
+                ::diesel::insert_into(t::table)
+                    .values((t::id.eq(0), t::txt.eq(txt), t::blb.eq(blb)))
+                    .execute(&mut conn)
+                    .unwrap();
+
+                // written as TEXT and BLOB
+                let (raw_txt, raw_blb): (String, Vec<u8>) = t::table
+                    .select((t::txt, t::blb))
+                    .filter(sql::<Bool>("typeof(txt) = 'text' AND typeof(blb) = 'blob'"))
+                    .first(&mut conn)
+                    .unwrap();
+                prop_assert_eq!(raw_txt, ::bs58::encode(buf).into_string());
+                prop_assert_eq!(raw_blb.as_slice(), buf.as_slice());
+
+                // read from TEXT and BLOB
+                let (from_txt, from_blb): (T, T) =
+                    t::table.select((t::txt, t::blb)).first(&mut conn).unwrap();
+                prop_assert_eq!(from_txt, expected);
+                prop_assert_eq!(from_blb, expected);
+
+                // filter
+                let n = t::table
+                    .filter(t::txt.eq(txt).and(t::blb.eq(blb)))
+                    .count()
+                    .get_result::<i64>(&mut conn)
+                    .unwrap();
+                prop_assert_eq!(n, 1);
+            }
+        }
+
+        #[test]
+        fn invalid_rejected() {
+            let mut conn = conn();
+            ::diesel::insert_into(t::table)
+                .values((
+                    t::id.eq(0),
+                    t::txt.eq("0"),
+                    t::blb.eq([0u8; BUF_LEN + 1].as_slice()),
+                ))
+                .execute(&mut conn)
+                .unwrap();
+            t::table.select(t::txt).first::<T>(&mut conn).unwrap_err();
+            t::table.select(t::blb).first::<T>(&mut conn).unwrap_err();
+        }
+    };
+
+    (@sqlx $t:ident, $text:expr, $b:ident, $blob:expr) => {
+        use ::sqlx::{Connection, SqliteConnection};
+
+        async fn conn() -> SqliteConnection {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            ::sqlx::query("CREATE TABLE t (txt TEXT, blb BLOB)")
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            conn
+        }
+
+        fn rt() -> ::tokio::runtime::Runtime {
+            ::tokio::runtime::Builder::new_current_thread()
+                .build()
+                .unwrap()
+        }
+
+        proptest! {
+            #[test]
+            fn round_trip(buf: [u8; BUF_LEN]) {
+                let expected = new(buf);
+                let (txt, blb) = ({ let $t = &expected; $text }, { let $b = &expected; $blob });
+                let ((txt, blb), (from_txt, from_blb)) = rt().block_on(async {
+                    let mut conn = conn().await;
+                    ::sqlx::query("INSERT INTO t (txt, blb) VALUES (?, ?)")
+                        .bind(txt)
+                        .bind(blb)
+                        .execute(&mut conn)
+                        .await
+                        .unwrap();
+                    let raw: (String, Vec<u8>) = ::sqlx::query_as(
+                        "SELECT txt, blb FROM t WHERE typeof(txt) = 'text' AND typeof(blb) = 'blob'",
+                    )
+                    .fetch_one(&mut conn)
+                    .await
+                    .unwrap();
+                    let typed: (T, T) = ::sqlx::query_as("SELECT txt, blb FROM t")
+                        .fetch_one(&mut conn)
+                        .await
+                        .unwrap();
+                    (raw, typed)
+                });
+
+                // encoded as TEXT and BLOB
+                prop_assert_eq!(txt, ::bs58::encode(buf).into_string());
+                prop_assert_eq!(blb.as_slice(), buf.as_slice());
+
+                // decoded from TEXT and BLOB
+                prop_assert_eq!(from_txt, expected);
+                prop_assert_eq!(from_blb, expected);
+            }
+        }
+
+        #[::tokio::test]
+        async fn invalid_rejected() {
+            let mut conn = conn().await;
+            ::sqlx::query("INSERT INTO t (txt, blb) VALUES (?, ?)")
+                .bind("0")
+                .bind([0u8; BUF_LEN + 1].as_slice())
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            ::sqlx::query_scalar::<_, T>("SELECT txt FROM t")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap_err();
+            ::sqlx::query_scalar::<_, T>("SELECT blb FROM t")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap_err();
+        }
+    };
+}
+
+pub(crate) use test_suite;
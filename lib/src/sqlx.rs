@@ -0,0 +1,103 @@
+//! [`Bs58String`] is encoded as a TEXT base58 string,
+//! or as a BLOB/BYTEA of the decoded `[u8; BUF_LEN]` through [`Bs58String::to_blob`].
+//!
+//! It can be decoded from either a TEXT column containing the base58 string
+//! or a BLOB/BYTEA column containing the decoded `[u8; BUF_LEN]`.
+
+use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type, ValueRef};
+
+use crate::{Bs58Blob, Bs58Str, Bs58String};
+
+impl<const MAX_STR_LEN: usize, DB: Database> Type<DB> for Bs58String<MAX_STR_LEN>
+where
+    str: Type<DB>,
+    [u8]: Type<DB>,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty) || <[u8] as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, const MAX_STR_LEN: usize, DB: Database> Encode<'q, DB> for Bs58String<MAX_STR_LEN>
+where
+    for<'a> &'a str: Encode<'q, DB>,
+{
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.as_str().encode_by_ref(buf)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.as_str().size_hint()
+    }
+}
+
+impl<const BUF_LEN: usize, DB: Database> Type<DB> for Bs58Blob<BUF_LEN>
+where
+    [u8]: Type<DB>,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        <[u8] as Type<DB>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <[u8] as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, const BUF_LEN: usize, DB: Database> Encode<'q, DB> for Bs58Blob<BUF_LEN>
+where
+    for<'a> &'a [u8]: Encode<'q, DB>,
+{
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.as_slice().encode_by_ref(buf)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.as_slice().size_hint()
+    }
+}
+
+impl<'r, const MAX_STR_LEN: usize, DB: Database> Decode<'r, DB> for Bs58String<MAX_STR_LEN>
+where
+    str: Type<DB>,
+    &'r str: Decode<'r, DB>,
+    &'r [u8]: Decode<'r, DB>,
+{
+    #[inline]
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        // some backends (e.g. sqlite) allow decoding TEXT values as bytes,
+        // so must check for TEXT first
+        if <str as Type<DB>>::compatible(&value.type_info()) {
+            let s = <&str as Decode<DB>>::decode(value)?;
+            Ok(Bs58Str::try_new(s)?.into())
+        } else {
+            let b = <&[u8] as Decode<DB>>::decode(value)?;
+            Ok(Self::try_encode_slice(b)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sql_tests::test_suite! {
+        sqlx,
+        Bs58String<MAX_STR_LEN>,
+        new: |buf| Bs58String::encode(&buf),
+        text: |s| *s,
+        blob: |s| s.to_blob::<BUF_LEN>(),
+    }
+}
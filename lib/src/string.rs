@@ -0,0 +1,122 @@
+//! Conversions between [`Bs58String`] and `alloc` types.
+//!
+//! [`Bs58String`] itself never allocates, so these are opt-in for `no_std` users
+//! that have an allocator, e.g. to call `to_string()` without wiring up [`core::fmt::Display`].
+
+use alloc::{borrow::Cow, boxed::Box, string::String};
+
+use crate::{Bs58Str, Bs58StrDecodeErr, Bs58String};
+
+/// alloc
+impl<const MAX_STR_LEN: usize> Bs58String<MAX_STR_LEN> {
+    #[inline]
+    pub fn into_string(self) -> String {
+        String::from(self.as_str())
+    }
+
+    #[inline]
+    pub fn encode_batch<const BUF_LEN: usize>(bufs: &[[u8; BUF_LEN]]) -> Box<[Self]> {
+        bufs.iter().map(Self::encode).collect()
+    }
+
+    #[inline]
+    pub fn decode_batch<const BUF_LEN: usize>(strs: &[Self]) -> Box<[[u8; BUF_LEN]]> {
+        strs.iter().map(Self::decode).collect()
+    }
+
+    /// Validates every str in `strs`, failing on the first invalid one
+    #[inline]
+    pub fn try_new_batch<S: AsRef<str>>(strs: &[S]) -> Result<Box<[Self]>, Bs58StrDecodeErr> {
+        strs.iter()
+            .map(|s| Bs58Str::try_new(s.as_ref()).map(Self::from_bs58_str))
+            .collect()
+    }
+}
+
+impl<const MAX_STR_LEN: usize> From<Bs58String<MAX_STR_LEN>> for String {
+    #[inline]
+    fn from(value: Bs58String<MAX_STR_LEN>) -> Self {
+        value.into_string()
+    }
+}
+
+impl<const MAX_STR_LEN: usize> From<Bs58Str<'_, MAX_STR_LEN>> for String {
+    #[inline]
+    fn from(value: Bs58Str<'_, MAX_STR_LEN>) -> Self {
+        String::from(value.as_str())
+    }
+}
+
+impl<const MAX_STR_LEN: usize> TryFrom<String> for Bs58String<MAX_STR_LEN> {
+    type Error = Bs58StrDecodeErr;
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Bs58Str::try_new(&value).map(Self::from_bs58_str)
+    }
+}
+
+impl<const MAX_STR_LEN: usize> TryFrom<Cow<'_, str>> for Bs58String<MAX_STR_LEN> {
+    type Error = Bs58StrDecodeErr;
+
+    #[inline]
+    fn try_from(value: Cow<'_, str>) -> Result<Self, Self::Error> {
+        Bs58Str::try_new(&value).map(Self::from_bs58_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+    use crate::proptest::{bs58_string, near_miss};
+
+    macro_rules! test_suite {
+        ($MAX_STR_LEN:expr, $BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(
+                    s in bs58_string::<$MAX_STR_LEN>(),
+                    miss in near_miss($BUF_LEN),
+                    bufs in vec(any::<[u8; $BUF_LEN]>(), 0..8),
+                ) {
+                    type S = Bs58String<$MAX_STR_LEN>;
+
+                    let string = s.into_string();
+                    prop_assert_eq!(string.as_str(), s.as_str());
+                    prop_assert_eq!(String::from(s), string.clone());
+                    prop_assert_eq!(String::from(s.as_bs58_str()), string.clone());
+
+                    prop_assert_eq!(S::try_from(Cow::Borrowed(string.as_str())).unwrap(), s);
+                    prop_assert_eq!(S::try_from(string).unwrap(), s);
+                    prop_assert!(S::try_from(Cow::Owned(miss.clone())).is_err());
+                    prop_assert!(S::try_from(miss).is_err());
+
+                    let encoded = S::encode_batch(&bufs);
+                    let expected: Vec<_> = bufs.iter().map(S::encode).collect();
+                    prop_assert_eq!(&*encoded, expected.as_slice());
+                    prop_assert_eq!(&*S::decode_batch::<$BUF_LEN>(&encoded), bufs.as_slice());
+                    let strs: Vec<String> = encoded.iter().map(|s| s.into_string()).collect();
+                    prop_assert_eq!(S::try_new_batch(&strs).unwrap(), encoded);
+                }
+            }
+        };
+    }
+
+    test_suite!(2, 1, b_2_1_test);
+    test_suite!(22, 16, b_22_16_test);
+    test_suite!(44, 32, b_44_32_test);
+    test_suite!(88, 64, b_88_64_test);
+
+    #[test]
+    fn try_new_batch_fails_on_invalid() {
+        let valid = Bs58String::<44>::encode(&[1u8; 32]).into_string();
+        assert_eq!(
+            Bs58String::<44>::try_new_batch(&[valid.as_str(), "1"]).unwrap_err(),
+            Bs58StrDecodeErr::NotOfBufLen
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use core::{fmt::Display, ops::Deref};
+
+use crate::{assert_lens, buf_len, Bs58Str, Bs58StrDecodeErr, Bs58String};
+
+/// A constant max-size base58-encoded string
+/// for encoding of buffers of any len up to `BUF_LEN = buf_len(MAX_STR_LEN)`.
+///
+/// Unlike [`Bs58String`], decoding does not reject payloads shorter than `BUF_LEN`,
+/// so the decoded len is returned alongside the buffer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Bs58VarString<const MAX_STR_LEN: usize>(Bs58String<MAX_STR_LEN>);
+
+/// Constructors
+impl<const MAX_STR_LEN: usize> Bs58VarString<MAX_STR_LEN> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(Bs58String::new())
+    }
+
+    /// Same as [`Self::decode_from_onto`], but returns an owned buffer
+    /// along with the decoded len
+    #[inline]
+    pub fn decode_from<const BUF_LEN: usize>(
+        from: &str,
+    ) -> Result<(Self, usize, [u8; BUF_LEN]), Bs58StrDecodeErr> {
+        let mut buf = [0u8; BUF_LEN];
+        let (res, len) = Self::decode_from_onto(from, &mut buf)?;
+        Ok((res, len, buf))
+    }
+
+    // Need to use a const generic with comptime assertion
+    // here instead of associated const
+    // because we cant do `-> [u8; Self::BUF_LEN]` yet
+    //
+    /// Attempts to create [`Self`] by decoding the given str `from` onto `buf`,
+    /// verifying that it is indeed a base58-encoded buffer of len at most `BUF_LEN`.
+    ///
+    /// Returns the decoded len. Bytes of `buf` past it are left untouched
+    #[inline]
+    pub fn decode_from_onto<const BUF_LEN: usize>(
+        from: &str,
+        buf: &mut [u8; BUF_LEN],
+    ) -> Result<(Self, usize), Bs58StrDecodeErr> {
+        const {
+            assert_lens(BUF_LEN, MAX_STR_LEN);
+        }
+
+        // all valid encodings of up to BUF_LEN bytes are at most MAX_STR_LEN long
+        if from.len() > MAX_STR_LEN {
+            return Err(Bs58StrDecodeErr::NotOfBufLen);
+        }
+        let len = bs58::decode(from).onto(buf).map_err(|e| match e {
+            bs58::decode::Error::BufferTooSmall => Bs58StrDecodeErr::NotOfBufLen,
+            e => Bs58StrDecodeErr::Bs58(e),
+        })?;
+
+        // from.len() <= MAX_STR_LEN checked above
+        Ok((Self(Bs58String::from_bs58_str(Bs58Str(from))), len))
+    }
+}
+
+/// Accessors
+impl<const MAX_STR_LEN: usize> Bs58VarString<MAX_STR_LEN> {
+    #[inline]
+    pub const fn as_slice(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+/// Codec
+impl<const MAX_STR_LEN: usize> Bs58VarString<MAX_STR_LEN> {
+    /// Max len of the encoded buffers
+    pub const BUF_LEN: usize = buf_len(MAX_STR_LEN);
+
+    #[inline]
+    pub fn encode<const N: usize>(buf: &[u8; N]) -> Self {
+        let mut res = Self::new();
+        res.encode_from(buf);
+        res
+    }
+
+    /// Encodes `buf` onto `self`, overwriting previous data
+    #[inline]
+    pub fn encode_from<const N: usize>(&mut self, buf: &[u8; N]) {
+        const {
+            assert_var_lens(N, MAX_STR_LEN);
+        }
+
+        // safety: len checked at compile time above
+        unsafe {
+            bs58::encode(buf).onto(&mut self.0).unwrap_unchecked();
+        }
+    }
+
+    /// Same as [`Self::encode`], but for a slice whose len is only known at runtime.
+    ///
+    /// Errors with [`Bs58StrDecodeErr::NotOfBufLen`] if `buf.len() > Self::BUF_LEN`
+    #[inline]
+    pub fn try_encode_slice(buf: &[u8]) -> Result<Self, Bs58StrDecodeErr> {
+        if buf.len() > Self::BUF_LEN {
+            return Err(Bs58StrDecodeErr::NotOfBufLen);
+        }
+        let mut res = Self::new();
+        // safety: len checked above
+        unsafe {
+            bs58::encode(buf).onto(&mut res.0).unwrap_unchecked();
+        }
+        Ok(res)
+    }
+
+    /// Returns the decoded len along with the buffer, whose bytes past it are zero
+    #[inline]
+    pub fn decode<const BUF_LEN: usize>(&self) -> (usize, [u8; BUF_LEN]) {
+        let mut buf = [0u8; BUF_LEN];
+        let len = self.decode_onto(&mut buf);
+        (len, buf)
+    }
+
+    /// Decodes `self` onto `buf`, overwriting previous data, and returns the decoded len.
+    /// Bytes of `buf` past it are left untouched
+    #[inline]
+    pub fn decode_onto<const BUF_LEN: usize>(&self, buf: &mut [u8; BUF_LEN]) -> usize {
+        const {
+            assert_lens(BUF_LEN, MAX_STR_LEN);
+        }
+
+        // safety: self is always a valid encoding of at most BUF_LEN bytes
+        unsafe { bs58::decode(self.as_str()).onto(buf).unwrap_unchecked() }
+    }
+}
+
+/// Panics (at compile-time, when called in a `const {}` block)
+/// if `MAX_STR_LEN`-long base58 strings cannot encode all `buf_len`-byte buffers,
+/// i.e. if `buf_len > buf_len(MAX_STR_LEN)`.
+///
+/// [`Bs58VarString`]'s `encode*` methods only require this, while its `decode*` methods
+/// still require [`assert_lens`] so that every valid [`Bs58VarString`] fits in the buffer.
+#[inline]
+pub const fn assert_var_lens(buf_len: usize, max_str_len: usize) {
+    assert!(
+        buf_len <= crate::buf_len(max_str_len),
+        "BUF_LEN > buf_len(MAX_STR_LEN). Use `bs58_fixed::max_str_len(BUF_LEN)` as MAX_STR_LEN"
+    );
+}
+
+// core traits
+
+impl<const MAX_STR_LEN: usize> Deref for Bs58VarString<MAX_STR_LEN> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const MAX_STR_LEN: usize> AsRef<str> for Bs58VarString<MAX_STR_LEN> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const MAX_STR_LEN: usize> AsRef<[u8]> for Bs58VarString<MAX_STR_LEN> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const MAX_STR_LEN: usize> Display for Bs58VarString<MAX_STR_LEN> {
+    /// Same as [`Bs58String`]'s [`Display`] impl
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    macro_rules! test_suite {
+        ($MAX_STR_LEN:expr, $BUF_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(
+                    v in vec(any::<u8>(), 0..=$BUF_LEN as usize),
+                    longer in vec(any::<u8>(), $BUF_LEN as usize + 1..=$BUF_LEN as usize + 2),
+                ) {
+                    type S = Bs58VarString<$MAX_STR_LEN>;
+
+                    // check against bs58 impl
+                    let encoded = S::try_encode_slice(&v).unwrap();
+                    let bs58_impl = bs58::encode(&v).into_string();
+                    prop_assert_eq!(bs58_impl.as_str(), encoded.as_str());
+
+                    // round-trip
+                    let (len, decoded) = encoded.decode::<$BUF_LEN>();
+                    prop_assert_eq!(&decoded[..len], v.as_slice());
+                    prop_assert!(decoded[len..].iter().all(|b| *b == 0));
+
+                    let (s, len, decoded) = S::decode_from::<$BUF_LEN>(&bs58_impl).unwrap();
+                    prop_assert_eq!(s, encoded);
+                    prop_assert_eq!(&decoded[..len], v.as_slice());
+
+                    // longer than BUF_LEN
+                    prop_assert_eq!(
+                        S::try_encode_slice(&longer).unwrap_err(),
+                        Bs58StrDecodeErr::NotOfBufLen
+                    );
+                    prop_assert_eq!(
+                        S::decode_from::<$BUF_LEN>(&bs58::encode(&longer).into_string()).unwrap_err(),
+                        Bs58StrDecodeErr::NotOfBufLen
+                    );
+                }
+            }
+        };
+    }
+
+    test_suite!(0, 0, b_0_0_test);
+    test_suite!(2, 1, b_2_1_test);
+    test_suite!(11, 8, b_11_8_test);
+    test_suite!(22, 16, b_22_16_test);
+    test_suite!(44, 32, b_44_32_test);
+    test_suite!(88, 64, b_88_64_test);
+
+    #[test]
+    fn fixed_size_encode() {
+        let buf = [0xffu8; 20];
+        assert_eq!(
+            Bs58VarString::<44>::encode(&buf).as_str(),
+            bs58::encode(buf).into_string()
+        );
+    }
+
+    #[test]
+    fn invalid_char() {
+        assert!(matches!(
+            Bs58VarString::<44>::decode_from::<32>("0"),
+            Err(Bs58StrDecodeErr::Bs58(_))
+        ));
+    }
+}
@@ -0,0 +1,56 @@
+//! Benchmarks passing 10k-element `Bs58Pk` vectors across the wasm boundary,
+//! against the previous implementation that converted each element through serde.
+//!
+//! Run with `make bench`
+
+use bs58_fixed_wasm_consumer::Bs58Pk;
+use serde::{Deserialize, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::{wasm_bindgen_bench, Criterion};
+
+const N: u32 = 10_000;
+
+/// Converts to and from js with tsify's derived wasm ABI impls,
+/// which (de)serialize each element with serde
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+#[serde(transparent)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+struct SerdePk(Bs58Pk);
+
+#[wasm_bindgen]
+extern "C" {
+    /// `Array.from(values)`, so that `values` is passed to js and back
+    #[wasm_bindgen(js_namespace = Array, js_name = from)]
+    fn round_trip(values: Box<[Bs58Pk]>) -> Box<[Bs58Pk]>;
+
+    /// Same as [`round_trip`], but for [`SerdePk`]
+    #[wasm_bindgen(js_namespace = Array, js_name = from)]
+    fn round_trip_serde(values: Box<[SerdePk]>) -> Box<[SerdePk]>;
+}
+
+fn pks() -> Box<[Bs58Pk]> {
+    (0..N)
+        .map(|i| {
+            let mut buf = [0xFF; 32];
+            buf[..4].copy_from_slice(&i.to_le_bytes());
+            Bs58Pk::new(buf)
+        })
+        .collect()
+}
+
+#[wasm_bindgen_bench]
+fn vec(c: &mut Criterion) {
+    let pks = pks();
+    let serde_pks: Box<[SerdePk]> = pks.iter().copied().map(SerdePk).collect();
+    assert_eq!(round_trip(pks.clone()), pks);
+    assert_eq!(round_trip_serde(serde_pks.clone()), serde_pks);
+
+    c.bench_function("10k Bs58Pk vector abi", |b| {
+        b.iter(|| round_trip(pks.clone()))
+    });
+
+    c.bench_function("10k Bs58Pk vector abi, serde per element", |b| {
+        b.iter(|| round_trip_serde(serde_pks.clone()))
+    });
+}
@@ -1,13 +1,19 @@
+use std::collections::{BTreeMap, HashMap};
+
 use bs58_fixed_wasm::Bs58Array;
 use serde::{Deserialize, Serialize};
 use tsify_next::Tsify;
-use wasm_bindgen::prelude::*;
+use wasm_bindgen::{convert::TryFromJsValue, prelude::*};
 
 /// Need this to also declare the type alias in ts
 /// if Bs58Pk is used as a field in other Tsify objects
 #[tsify_next::declare]
 pub type Bs58Pk = Bs58Array<32, 44>;
 
+bs58_fixed_wasm::declare_bs58_array_guards!(Bs58Pk, isBs58Pk, tryParseBs58Pk);
+
+bs58_fixed_wasm::declare_bs58_array_class!(Bs58PkClass, Bs58Pk);
+
 /// This fn:
 /// - takes in a base58 encoded 32-byte buffer
 /// - sets the last byte to 0
@@ -40,6 +46,63 @@ pub fn zero_last_vec(s: Box<[Bs58Pk]>) -> Box<[Bs58Pk]> {
     s.iter().map(zero_last_ref).collect()
 }
 
+/// Same as {@link zeroLast}, but takes and returns the class wrapper
+#[wasm_bindgen(js_name = zeroLastClass)]
+pub fn zero_last_class(s: &Bs58PkClass) -> Bs58PkClass {
+    zero_last_ref(&s.0).into()
+}
+
+/// Same as {@link zeroLastVec}, but takes and returns `Vec`s instead of boxed slices
+#[wasm_bindgen(js_name = zeroLastVecParam)]
+pub fn zero_last_vec_param(s: Vec<Bs58Pk>) -> Vec<Bs58Pk> {
+    s.into_iter().map(zero_last).collect()
+}
+
+/// Same as {@link zeroLastVecParam}, but operates on option
+#[wasm_bindgen(js_name = zeroLastOptVec)]
+pub fn zero_last_opt_vec(s: Option<Vec<Bs58Pk>>) -> Option<Vec<Bs58Pk>> {
+    s.map(zero_last_vec_param)
+}
+
+/// Same as {@link zeroLast}, but takes any js value and returns
+/// undefined if it is not a valid Bs58Pk, to test `TryFromJsValue`
+#[wasm_bindgen(js_name = zeroLastJsValue)]
+pub fn zero_last_js_value(s: JsValue) -> Option<Bs58Pk> {
+    Bs58Pk::try_from_js_value(s).ok().map(zero_last)
+}
+
+/// Returns `false` for off-curve keys e.g. program-derived addresses
+#[wasm_bindgen(js_name = isOnCurve)]
+pub fn is_on_curve(s: &Bs58Pk) -> bool {
+    s.is_on_curve()
+}
+
+/// A `#[wasm_bindgen]` struct with Bs58Pk fields,
+/// to test that Bs58Pk works with auto-generated getters and setters
+#[wasm_bindgen]
+pub struct ZeroLastStruct {
+    pub pk: Bs58Pk,
+    pub opt: Option<Bs58Pk>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub pks: Vec<Bs58Pk>,
+}
+
+#[wasm_bindgen]
+impl ZeroLastStruct {
+    #[wasm_bindgen(constructor)]
+    pub fn new(pk: Bs58Pk, opt: Option<Bs58Pk>, pks: Vec<Bs58Pk>) -> Self {
+        Self { pk, opt, pks }
+    }
+
+    /// Applies {@link zeroLast} to all fields in place
+    #[wasm_bindgen(js_name = zeroLast)]
+    pub fn zero_last(&mut self) {
+        self.pk = zero_last(self.pk);
+        self.opt = self.opt.map(zero_last);
+        self.pks.iter_mut().for_each(|pk| *pk = zero_last(*pk));
+    }
+}
+
 #[derive(Serialize, Deserialize, Tsify)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ZeroLastObj {
@@ -87,3 +150,63 @@ pub fn zero_last_vec_obj(ZeroLastVecObj { arg }: ZeroLastVecObj) -> ZeroLastVecO
         arg: zero_last_vec(arg),
     }
 }
+
+#[derive(Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[cfg_attr(feature = "js", tsify(hashmap_as_object))]
+pub struct ZeroLastHashMapObj {
+    pub arg: HashMap<Bs58Pk, u32>,
+}
+
+/// Same as {@link zeroLast}, but for every key of the map,
+/// to test that Bs58Pk can be used as keys of `Record`s.
+///
+/// Values of keys that collide after zeroing are summed
+#[wasm_bindgen(js_name = zeroLastHashMapObj)]
+pub fn zero_last_hash_map_obj(
+    ZeroLastHashMapObj { arg }: ZeroLastHashMapObj,
+) -> ZeroLastHashMapObj {
+    let mut res = HashMap::with_capacity(arg.len());
+    arg.into_iter()
+        .for_each(|(k, v)| *res.entry(zero_last(k)).or_default() += v);
+    ZeroLastHashMapObj { arg: res }
+}
+
+/// Same as {@link ZeroLastHashMapObj}, but without `hashmap_as_object`,
+/// so that `arg` is a js `Map`.
+///
+/// Only with the `js` feature, since maps are always objects with `json`
+#[cfg(feature = "js")]
+#[derive(Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ZeroLastJsMapObj {
+    pub arg: HashMap<Bs58Pk, u32>,
+}
+
+/// Same as {@link zeroLastHashMapObj}, but for js `Map`s,
+/// to test that Bs58Pk can be used as keys of `Map`s
+#[cfg(feature = "js")]
+#[wasm_bindgen(js_name = zeroLastJsMapObj)]
+pub fn zero_last_js_map_obj(ZeroLastJsMapObj { arg }: ZeroLastJsMapObj) -> ZeroLastJsMapObj {
+    ZeroLastJsMapObj {
+        arg: zero_last_hash_map_obj(ZeroLastHashMapObj { arg }).arg,
+    }
+}
+
+#[derive(Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[cfg_attr(feature = "js", tsify(hashmap_as_object))]
+pub struct ZeroLastBTreeMapObj {
+    pub arg: BTreeMap<Bs58Pk, u32>,
+}
+
+/// Same as {@link zeroLastHashMapObj}, but for `BTreeMap`
+#[wasm_bindgen(js_name = zeroLastBTreeMapObj)]
+pub fn zero_last_btree_map_obj(
+    ZeroLastBTreeMapObj { arg }: ZeroLastBTreeMapObj,
+) -> ZeroLastBTreeMapObj {
+    let mut res = BTreeMap::new();
+    arg.into_iter()
+        .for_each(|(k, v)| *res.entry(zero_last(k)).or_default() += v);
+    ZeroLastBTreeMapObj { arg: res }
+}
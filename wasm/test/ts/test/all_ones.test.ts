@@ -22,6 +22,12 @@ describe("allOnes", () => {
     expect(zeroLast(ALL_ONES)).toStrictEqual(ALL_ONES_ZERO_LAST);
   });
 
+  it("zeroLast invalid throws", () => {
+    expect(() => zeroLast("0")).toThrow();
+    expect(() => zeroLast(ALL_ZEROS.slice(1))).toThrow();
+    expect(() => zeroLast(1 as unknown as Bs58Pk)).toThrow();
+  });
+
   it("zeroLastRef", () => {
     expect(zeroLastRef(ALL_ONES)).toStrictEqual(ALL_ONES_ZERO_LAST);
   });
@@ -0,0 +1,72 @@
+import {
+  ZeroLastStruct,
+  zeroLastJsValue,
+  zeroLastOptVec,
+  zeroLastVecParam,
+  type Bs58Pk,
+} from "bs58-fixed-wasm-consumer";
+import { describe, expect, it } from "vitest";
+
+const ALL_ONES: Bs58Pk = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+const ALL_ONES_ZERO_LAST: Bs58Pk =
+  "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKh";
+
+const ALL_ZEROS: Bs58Pk = "11111111111111111111111111111111";
+
+describe("Vec params", () => {
+  it("zeroLastVecParam", () => {
+    expect(zeroLastVecParam([ALL_ONES, ALL_ZEROS])).toStrictEqual([
+      ALL_ONES_ZERO_LAST,
+      ALL_ZEROS,
+    ]);
+    expect(() => zeroLastVecParam(["0"])).toThrow();
+  });
+
+  it("zeroLastOptVec", () => {
+    expect(zeroLastOptVec(undefined)).toStrictEqual(undefined);
+    expect(zeroLastOptVec([ALL_ONES])).toStrictEqual([ALL_ONES_ZERO_LAST]);
+  });
+});
+
+describe("TryFromJsValue", () => {
+  it("zeroLastJsValue", () => {
+    expect(zeroLastJsValue(ALL_ONES)).toStrictEqual(ALL_ONES_ZERO_LAST);
+    expect(zeroLastJsValue("0")).toStrictEqual(undefined);
+    expect(zeroLastJsValue(1)).toStrictEqual(undefined);
+    expect(zeroLastJsValue({})).toStrictEqual(undefined);
+  });
+});
+
+describe("ZeroLastStruct", () => {
+  it("getters", () => {
+    const s = new ZeroLastStruct(ALL_ONES, undefined, [ALL_ONES]);
+    expect(s.pk).toStrictEqual(ALL_ONES);
+    expect(s.opt).toStrictEqual(undefined);
+    expect(s.pks).toStrictEqual([ALL_ONES]);
+    s.zeroLast();
+    expect(s.pk).toStrictEqual(ALL_ONES_ZERO_LAST);
+    expect(s.pks).toStrictEqual([ALL_ONES_ZERO_LAST]);
+  });
+
+  it("setters", () => {
+    const s = new ZeroLastStruct(ALL_ZEROS, undefined, []);
+    s.pk = ALL_ONES;
+    s.opt = ALL_ONES;
+    s.pks = [ALL_ONES, ALL_ZEROS];
+    s.zeroLast();
+    expect(s.pk).toStrictEqual(ALL_ONES_ZERO_LAST);
+    expect(s.opt).toStrictEqual(ALL_ONES_ZERO_LAST);
+    expect(s.pks).toStrictEqual([ALL_ONES_ZERO_LAST, ALL_ZEROS]);
+    s.opt = null;
+    expect(s.opt).toStrictEqual(undefined);
+  });
+
+  it("invalid setter throws", () => {
+    const s = new ZeroLastStruct(ALL_ZEROS, undefined, []);
+    expect(() => {
+      s.pk = "0";
+    }).toThrow();
+    expect(s.pk).toStrictEqual(ALL_ZEROS);
+  });
+});
@@ -0,0 +1,42 @@
+import { Bs58PkClass, zeroLastClass } from "bs58-fixed-wasm-consumer";
+import { describe, expect, it } from "vitest";
+
+const ALL_ONES = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+const ALL_ONES_ZERO_LAST = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKh";
+
+const ALL_ONES_BYTES = new Uint8Array(32).fill(1);
+
+describe("Bs58PkClass", () => {
+  it("constructor", () => {
+    expect(new Bs58PkClass(ALL_ONES).toBytes()).toStrictEqual(ALL_ONES_BYTES);
+    expect(() => new Bs58PkClass("0")).toThrow();
+  });
+
+  it("fromBytes", () => {
+    expect(Bs58PkClass.fromBytes(ALL_ONES_BYTES).toString()).toStrictEqual(
+      ALL_ONES
+    );
+    expect(() => Bs58PkClass.fromBytes(new Uint8Array(31))).toThrow();
+  });
+
+  it("toString", () => {
+    expect(`${new Bs58PkClass(ALL_ONES)}`).toStrictEqual(ALL_ONES);
+    expect(JSON.stringify({ pk: new Bs58PkClass(ALL_ONES) })).toStrictEqual(
+      JSON.stringify({ pk: ALL_ONES })
+    );
+  });
+
+  it("equals", () => {
+    const a = new Bs58PkClass(ALL_ONES);
+    expect(a.equals(Bs58PkClass.fromBytes(ALL_ONES_BYTES))).toBe(true);
+    expect(a.equals(new Bs58PkClass(ALL_ONES_ZERO_LAST))).toBe(false);
+  });
+
+  it("zeroLastClass", () => {
+    const a = new Bs58PkClass(ALL_ONES);
+    expect(zeroLastClass(a).toString()).toStrictEqual(ALL_ONES_ZERO_LAST);
+    // passed by ref, so a is still usable
+    expect(a.toString()).toStrictEqual(ALL_ONES);
+  });
+});
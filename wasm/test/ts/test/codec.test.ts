@@ -0,0 +1,68 @@
+import {
+  bs58Decode16,
+  bs58Decode32,
+  bs58Decode64,
+  bs58Encode16,
+  bs58Encode20,
+  bs58Encode32,
+  bs58Encode64,
+  bs58IsValid20,
+  bs58IsValid32,
+} from "bs58-fixed-wasm-consumer";
+import { describe, expect, it } from "vitest";
+
+const ALL_ONES_32 = new Uint8Array(32).fill(1);
+
+const ALL_ONES_32_STR = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+const ALL_FF_64_STR =
+  "67rpwLCuS5DGA8KGZXKsVQ7dnPb9goRLoKfgGbLfQg9WoLUgNY77E2jT11fem3coV9nAkguBACzrU1iyZM4B8roQ";
+
+describe("codec", () => {
+  it("encode", () => {
+    expect(bs58Encode32(ALL_ONES_32)).toStrictEqual(ALL_ONES_32_STR);
+    expect(bs58Encode16(new Uint8Array(16))).toStrictEqual("1111111111111111");
+    expect(bs58Encode20(new Uint8Array(20))).toStrictEqual(
+      "11111111111111111111"
+    );
+    expect(bs58Encode64(new Uint8Array(64).fill(0xff))).toStrictEqual(
+      ALL_FF_64_STR
+    );
+  });
+
+  it("encode wrong len throws", () => {
+    expect(() => bs58Encode32(new Uint8Array(31))).toThrow();
+    expect(() => bs58Encode32(new Uint8Array(33))).toThrow();
+  });
+
+  it("decode", () => {
+    expect(bs58Decode32(ALL_ONES_32_STR)).toStrictEqual(ALL_ONES_32);
+    expect(bs58Decode16("1111111111111111")).toStrictEqual(
+      new Uint8Array(16)
+    );
+    expect(bs58Decode64(ALL_FF_64_STR)).toStrictEqual(
+      new Uint8Array(64).fill(0xff)
+    );
+  });
+
+  it("decode invalid throws", () => {
+    // bad char
+    expect(() => bs58Decode32("0" + ALL_ONES_32_STR.slice(1))).toThrow();
+    // 33 bytes
+    expect(() => bs58Decode32("1" + ALL_ONES_32_STR)).toThrow();
+    // 31 bytes
+    expect(() => bs58Decode32("1111111111111111111111111111111")).toThrow();
+  });
+
+  it("isValid", () => {
+    expect(bs58IsValid32(ALL_ONES_32_STR)).toStrictEqual(true);
+    expect(bs58IsValid32("11111111111111111111111111111111")).toStrictEqual(
+      true
+    );
+    expect(bs58IsValid32("0" + ALL_ONES_32_STR.slice(1))).toStrictEqual(false);
+    expect(bs58IsValid32("1" + ALL_ONES_32_STR)).toStrictEqual(false);
+    expect(bs58IsValid32("")).toStrictEqual(false);
+    expect(bs58IsValid20("11111111111111111111")).toStrictEqual(true);
+    expect(bs58IsValid20(ALL_ONES_32_STR)).toStrictEqual(false);
+  });
+});
@@ -0,0 +1,26 @@
+import { isOnCurve } from "bs58-fixed-wasm-consumer";
+import { describe, expect, it } from "vitest";
+
+const ALL_ONES = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+const ALL_ZEROS = "11111111111111111111111111111111";
+
+/**
+ * y = 2, which is not the y-coordinate of any point on the curve
+ */
+const OFF_CURVE = "8opHzTAnfzRpPEx21XtnrVTX28YQuCpAjcn1PczScKh";
+
+describe("isOnCurve", () => {
+  it("on curve", () => {
+    expect(isOnCurve(ALL_ONES)).toBe(true);
+    expect(isOnCurve(ALL_ZEROS)).toBe(true);
+  });
+
+  it("off curve", () => {
+    expect(isOnCurve(OFF_CURVE)).toBe(false);
+  });
+
+  it("invalid throws", () => {
+    expect(() => isOnCurve("0")).toThrow();
+  });
+});
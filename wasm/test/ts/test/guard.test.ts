@@ -0,0 +1,50 @@
+import { isBs58Pk, tryParseBs58Pk, type Bs58Pk } from "bs58-fixed-wasm-consumer";
+import { describe, expect, it } from "vitest";
+
+const ALL_ONES: Bs58Pk = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+const INVALID = [
+  // bad char
+  "0vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi",
+  // 33 bytes
+  "14vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi",
+  // 31 bytes
+  "1111111111111111111111111111111",
+  "",
+];
+
+const NOT_STRINGS = [undefined, null, 1, {}, [ALL_ONES], new Uint8Array(32)];
+
+describe("guard", () => {
+  it("isBs58Pk valid", () => {
+    const x: unknown = ALL_ONES;
+    expect(isBs58Pk(x)).toStrictEqual(true);
+    if (isBs58Pk(x)) {
+      const pk: Bs58Pk = x;
+      expect(pk).toStrictEqual(ALL_ONES);
+    }
+  });
+
+  it("isBs58Pk invalid", () => {
+    for (const x of [...INVALID, ...NOT_STRINGS]) {
+      expect(isBs58Pk(x)).toStrictEqual(false);
+    }
+  });
+
+  it("tryParseBs58Pk valid", () => {
+    expect(tryParseBs58Pk(ALL_ONES)).toStrictEqual({
+      ok: true,
+      value: ALL_ONES,
+    });
+  });
+
+  it("tryParseBs58Pk invalid does not throw", () => {
+    for (const x of [...INVALID, ...NOT_STRINGS]) {
+      const res = tryParseBs58Pk(x);
+      expect(res.ok).toStrictEqual(false);
+      if (!res.ok) {
+        expect(typeof res.error).toStrictEqual("string");
+      }
+    }
+  });
+});
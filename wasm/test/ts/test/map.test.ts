@@ -0,0 +1,81 @@
+import * as consumer from "bs58-fixed-wasm-consumer";
+import {
+  zeroLastBTreeMapObj,
+  zeroLastHashMapObj,
+  type Bs58Pk,
+} from "bs58-fixed-wasm-consumer";
+import { describe, expect, it } from "vitest";
+
+const ALL_ONES: Bs58Pk = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+const ALL_ONES_ZERO_LAST: Bs58Pk =
+  "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKh";
+
+const ALL_ZEROS: Bs58Pk = "11111111111111111111111111111111";
+
+describe.each([
+  ["zeroLastHashMapObj", zeroLastHashMapObj],
+  ["zeroLastBTreeMapObj", zeroLastBTreeMapObj],
+])("%s", (_, zeroLastMapObj) => {
+  it("empty", () => {
+    expect(zeroLastMapObj({ arg: {} })).toStrictEqual({ arg: {} });
+  });
+
+  it("keys", () => {
+    expect(
+      zeroLastMapObj({ arg: { [ALL_ONES]: 1, [ALL_ZEROS]: 2 } })
+    ).toStrictEqual({ arg: { [ALL_ONES_ZERO_LAST]: 1, [ALL_ZEROS]: 2 } });
+  });
+
+  it("colliding keys", () => {
+    expect(
+      zeroLastMapObj({ arg: { [ALL_ONES]: 1, [ALL_ONES_ZERO_LAST]: 2 } })
+    ).toStrictEqual({ arg: { [ALL_ONES_ZERO_LAST]: 3 } });
+  });
+
+  it("invalid key throws", () => {
+    expect(() => zeroLastMapObj({ arg: { "0": 1 } })).toThrow();
+  });
+});
+
+// only exported by the `js` build of the consumer crate
+const { zeroLastJsMapObj } = consumer as Partial<typeof consumer>;
+
+describe.skipIf(zeroLastJsMapObj === undefined)("zeroLastJsMapObj", () => {
+  const zeroLastMap = (arg: Map<Bs58Pk, number>) => zeroLastJsMapObj!({ arg }).arg;
+
+  it("empty", () => {
+    expect(zeroLastMap(new Map())).toStrictEqual(new Map());
+  });
+
+  it("keys", () => {
+    expect(
+      zeroLastMap(
+        new Map([
+          [ALL_ONES, 1],
+          [ALL_ZEROS, 2],
+        ])
+      )
+    ).toStrictEqual(
+      new Map([
+        [ALL_ONES_ZERO_LAST, 1],
+        [ALL_ZEROS, 2],
+      ])
+    );
+  });
+
+  it("colliding keys", () => {
+    expect(
+      zeroLastMap(
+        new Map([
+          [ALL_ONES, 1],
+          [ALL_ONES_ZERO_LAST, 2],
+        ])
+      )
+    ).toStrictEqual(new Map([[ALL_ONES_ZERO_LAST, 3]]));
+  });
+
+  it("invalid key throws", () => {
+    expect(() => zeroLastMap(new Map([["0", 1]]))).toThrow();
+  });
+});
@@ -0,0 +1,19 @@
+import {
+  zeroLastVec,
+  zeroLastVecObj,
+  type Bs58Pk,
+} from "bs58-fixed-wasm-consumer";
+import { bench, describe } from "vitest";
+import { ALL_ONES, N } from "./vec.common";
+
+const ARR = new Array<Bs58Pk>(N).fill(ALL_ONES);
+
+describe(`${N} elements`, () => {
+  bench("zeroLastVec (wasm abi)", () => {
+    zeroLastVec(ARR);
+  });
+
+  bench("zeroLastVecObj (serde)", () => {
+    zeroLastVecObj({ arg: ARR });
+  });
+});
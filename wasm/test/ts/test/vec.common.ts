@@ -0,0 +1,8 @@
+import type { Bs58Pk } from "bs58-fixed-wasm-consumer";
+
+export const N = 10_000;
+
+export const ALL_ONES: Bs58Pk = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+
+export const ALL_ONES_ZERO_LAST: Bs58Pk =
+  "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKh";
@@ -0,0 +1,42 @@
+import {
+  zeroLast,
+  zeroLastOptVec,
+  zeroLastVec,
+  zeroLastVecParam,
+  type Bs58Pk,
+} from "bs58-fixed-wasm-consumer";
+import { describe, expect, it } from "vitest";
+import { ALL_ONES, ALL_ONES_ZERO_LAST, N } from "./vec.common";
+
+describe("vec", () => {
+  it("empty", () => {
+    expect(zeroLastVec([])).toStrictEqual([]);
+  });
+
+  it(`${N} elements`, () => {
+    const res = zeroLastVec(new Array<Bs58Pk>(N).fill(ALL_ONES));
+    expect(res).toStrictEqual(new Array<Bs58Pk>(N).fill(ALL_ONES_ZERO_LAST));
+  });
+
+  it("non-string element throws", () => {
+    expect(() => zeroLastVec([ALL_ONES, 1 as unknown as Bs58Pk])).toThrow();
+    expect(() => zeroLastVec([ALL_ONES, null as unknown as Bs58Pk])).toThrow();
+    expect(() => zeroLastVec([ALL_ONES, 1n as unknown as Bs58Pk])).toThrow();
+  });
+
+  it("string object element throws like single values do", () => {
+    const obj = new String(ALL_ONES) as unknown as Bs58Pk;
+    expect(() => zeroLast(obj)).toThrow();
+    expect(() => zeroLastVec([obj])).toThrow();
+    expect(() => zeroLastVecParam([obj])).toThrow();
+    expect(() => zeroLastOptVec([obj])).toThrow();
+  });
+
+  it("invalid element throws", () => {
+    expect(() => zeroLastVec([ALL_ONES, "0"])).toThrow();
+  });
+
+  it("joined elements throw", () => {
+    expect(() => zeroLastVec([`${ALL_ONES},${ALL_ONES}`])).toThrow();
+  });
+});
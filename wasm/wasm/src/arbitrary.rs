@@ -0,0 +1,19 @@
+//! [`Bs58Array`] is generated as an arbitrary `[u8; BUF_LEN]`
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::Bs58Array;
+
+impl<'a, const BUF_LEN: usize, const MAX_STR_LEN: usize> Arbitrary<'a>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        <[u8; BUF_LEN]>::arbitrary(u).map(Self)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; BUF_LEN]>::size_hint(depth)
+    }
+}
@@ -0,0 +1,60 @@
+//! Borsh serializes [`Bs58Array`] as its raw `[u8; BUF_LEN]`
+
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::Bs58Array;
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> BorshSerialize
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> BorshDeserialize
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        <[u8; BUF_LEN]>::deserialize_reader(reader).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    macro_rules! test_suite {
+        ($BUF_LEN:expr, $MAX_STR_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(buf: [u8; $BUF_LEN]) {
+                    type A = Bs58Array<$BUF_LEN, $MAX_STR_LEN>;
+
+                    let a = A::new(buf);
+                    let ser = borsh::to_vec(&a).unwrap();
+                    prop_assert_eq!(ser.as_slice(), buf.as_slice());
+                    prop_assert_eq!(A::try_from_slice(&ser).unwrap(), a);
+
+                    // too short
+                    if $BUF_LEN > 0 {
+                        A::try_from_slice(&ser[1..]).unwrap_err();
+                    }
+                }
+            }
+        };
+    }
+
+    test_suite!(0, 0, b_0_0_test);
+    test_suite!(1, 2, b_1_2_test);
+    test_suite!(16, 22, b_16_22_test);
+    test_suite!(32, 44, b_32_44_test);
+    test_suite!(64, 88, b_64_88_test);
+}
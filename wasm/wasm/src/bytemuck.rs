@@ -0,0 +1,65 @@
+//! [`Bs58Array`] is `#[repr(transparent)]` over `[u8; BUF_LEN]`,
+//! so it can be cast to and from raw bytes
+
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+use crate::Bs58Array;
+
+// safety: repr(transparent) over [u8; BUF_LEN], which is all-zeros valid
+unsafe impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Zeroable
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+}
+
+// safety: repr(transparent) over [u8; BUF_LEN], which is Copy, 'static,
+// has no padding and any bit pattern is valid
+unsafe impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Pod
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+}
+
+// safety: repr(transparent) over [u8; BUF_LEN]
+unsafe impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> TransparentWrapper<[u8; BUF_LEN]>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    type Pk = Bs58Array<32, 44>;
+
+    #[derive(Clone, Copy, Pod, Zeroable)]
+    #[repr(C)]
+    struct Account {
+        owner: Pk,
+        mint: Pk,
+    }
+
+    proptest! {
+        #[test]
+        fn cast_round_trip(bufs in vec(any::<[u8; 32]>(), 0..=8)) {
+            let data = bufs.concat();
+            let pks: &[Pk] = bytemuck::cast_slice(&data);
+            prop_assert_eq!(pks, bufs.iter().copied().map(Pk::new).collect::<Vec<_>>());
+            prop_assert_eq!(bytemuck::cast_slice::<Pk, u8>(pks), data.as_slice());
+        }
+
+        #[test]
+        fn pod_struct(data: [u8; 64]) {
+            let acc: &Account = bytemuck::from_bytes(&data);
+            prop_assert_eq!(&acc.owner.0, Pk::peel_ref(&acc.owner));
+            prop_assert_eq!(acc.owner.0.as_slice(), &data[..32]);
+            prop_assert_eq!(acc.mint.0.as_slice(), &data[32..]);
+            prop_assert_eq!(Pk::wrap_ref(&acc.mint.0), &acc.mint);
+        }
+    }
+
+    #[test]
+    fn zeroed() {
+        assert_eq!(Pk::zeroed(), Pk::ZERO);
+    }
+}
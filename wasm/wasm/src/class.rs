@@ -0,0 +1,93 @@
+//! Opt-in js class wrapper for [`crate::Bs58Array`]s.
+//!
+//! Use [`crate::declare_bs58_array_class`] to declare one for a type alias.
+
+/// Declares `$Class`, a `#[wasm_bindgen]` class wrapping the [`crate::Bs58Array`]
+/// type alias `$Alias`, for js consumers that prefer an object holding the bytes
+/// over a bare base58 string.
+///
+/// In js, the class has:
+/// - `new $Class(s: string)`, which throws if `s` is not a valid `$Alias`
+/// - `static fromBytes(bytes: Uint8Array): $Class`, which throws if `bytes` is not of the correct length
+/// - `toBytes(): Uint8Array`
+/// - `toString(): string` and `toJSON(): string`, both returning the base58 encoding
+/// - `equals(other: $Class): boolean`
+///
+/// Like any other `#[wasm_bindgen]` class, `$Class` can be passed to and returned from
+/// `#[wasm_bindgen]` fns. Take it by reference (`&$Class`) to not consume the js object.
+///
+/// # Example
+///
+/// ```ignore
+/// #[tsify_next::declare]
+/// pub type Bs58Pk = Bs58Array<32, 44>;
+///
+/// bs58_fixed_wasm::declare_bs58_array_class!(Bs58PkClass, Bs58Pk);
+/// ```
+#[macro_export]
+macro_rules! declare_bs58_array_class {
+    ($Class:ident, $Alias:ty $(,)?) => {
+        #[$crate::__wasm_bindgen::prelude::wasm_bindgen(wasm_bindgen = $crate::__wasm_bindgen)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(transparent)]
+        pub struct $Class(#[wasm_bindgen(skip)] pub $Alias);
+
+        #[$crate::__wasm_bindgen::prelude::wasm_bindgen(wasm_bindgen = $crate::__wasm_bindgen)]
+        impl $Class {
+            #[wasm_bindgen(constructor)]
+            pub fn new(s: &str) -> Result<$Class, $crate::__wasm_bindgen::JsError> {
+                s.parse::<$Alias>()
+                    .map(Self)
+                    .map_err(|e| $crate::__wasm_bindgen::JsError::new(&e.to_string()))
+            }
+
+            #[wasm_bindgen(js_name = fromBytes)]
+            pub fn from_bytes(bytes: &[u8]) -> Result<$Class, $crate::__wasm_bindgen::JsError> {
+                <$Alias>::try_from(bytes)
+                    .map(Self)
+                    .map_err(|e| $crate::__wasm_bindgen::JsError::new(&e.to_string()))
+            }
+
+            #[wasm_bindgen(js_name = toBytes)]
+            pub fn to_bytes(&self) -> Vec<u8> {
+                self.0 .0.to_vec()
+            }
+
+            #[allow(clippy::inherent_to_string_shadow_display)]
+            #[wasm_bindgen(js_name = toString)]
+            pub fn to_string(&self) -> String {
+                self.0.to_string()
+            }
+
+            #[wasm_bindgen(js_name = toJSON)]
+            pub fn to_json(&self) -> String {
+                self.0.to_string()
+            }
+
+            pub fn equals(&self, other: &$Class) -> bool {
+                self == other
+            }
+        }
+
+        impl ::core::fmt::Display for $Class {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl From<$Alias> for $Class {
+            #[inline]
+            fn from(value: $Alias) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$Class> for $Alias {
+            #[inline]
+            fn from(value: $Class) -> Self {
+                value.0
+            }
+        }
+    };
+}
@@ -0,0 +1,85 @@
+//! Standalone base58 codec functions exported to js for common buffer sizes.
+//!
+//! These have exactly the same validation rules as [`Bs58Str`],
+//! so they can be used to replace a separate js bs58 library.
+
+use bs58_fixed::{max_str_len, Bs58Str, Bs58StrDecodeErr, Bs58String};
+use wasm_bindgen::prelude::*;
+
+#[inline]
+fn encode<const BUF_LEN: usize, const MAX_STR_LEN: usize>(buf: &[u8]) -> Result<String, JsError> {
+    let buf: &[u8; BUF_LEN] = buf.try_into().map_err(|_| Bs58StrDecodeErr::NotOfBufLen)?;
+    Ok(Bs58String::<MAX_STR_LEN>::encode(buf).as_str().to_owned())
+}
+
+#[inline]
+fn decode<const BUF_LEN: usize, const MAX_STR_LEN: usize>(s: &str) -> Result<Vec<u8>, JsError> {
+    let (_, buf) = Bs58Str::<MAX_STR_LEN>::decode_from::<BUF_LEN>(s)?;
+    Ok(buf.to_vec())
+}
+
+#[inline]
+fn is_valid<const MAX_STR_LEN: usize>(s: &str) -> bool {
+    Bs58Str::<MAX_STR_LEN>::try_new(s).is_ok()
+}
+
+macro_rules! export_codec {
+    (
+        $BUF_LEN:literal,
+        $encode:ident = $encode_js:literal,
+        $decode:ident = $decode_js:literal,
+        $is_valid:ident = $is_valid_js:literal $(,)?
+    ) => {
+        /// Encodes `buf` into a base58 string.
+        ///
+        /// Throws if `buf` is not of the byte length in this function's name.
+        #[wasm_bindgen(js_name = $encode_js)]
+        pub fn $encode(buf: &[u8]) -> Result<String, JsError> {
+            encode::<$BUF_LEN, { max_str_len($BUF_LEN) }>(buf)
+        }
+
+        /// Decodes base58 string `s` into bytes.
+        ///
+        /// Throws if `s` is not a valid base58 encoding of a buffer
+        /// of the byte length in this function's name.
+        #[wasm_bindgen(js_name = $decode_js)]
+        pub fn $decode(s: &str) -> Result<Vec<u8>, JsError> {
+            decode::<$BUF_LEN, { max_str_len($BUF_LEN) }>(s)
+        }
+
+        /// Returns true if `s` is a valid base58 encoding of a buffer
+        /// of the byte length in this function's name
+        #[wasm_bindgen(js_name = $is_valid_js)]
+        pub fn $is_valid(s: &str) -> bool {
+            is_valid::<{ max_str_len($BUF_LEN) }>(s)
+        }
+    };
+}
+
+export_codec!(
+    16,
+    encode16 = "bs58Encode16",
+    decode16 = "bs58Decode16",
+    is_valid16 = "bs58IsValid16",
+);
+
+export_codec!(
+    20,
+    encode20 = "bs58Encode20",
+    decode20 = "bs58Decode20",
+    is_valid20 = "bs58IsValid20",
+);
+
+export_codec!(
+    32,
+    encode32 = "bs58Encode32",
+    decode32 = "bs58Decode32",
+    is_valid32 = "bs58IsValid32",
+);
+
+export_codec!(
+    64,
+    encode64 = "bs58Encode64",
+    decode64 = "bs58Decode64",
+    is_valid64 = "bs58IsValid64",
+);
@@ -0,0 +1,80 @@
+//! [`Bs58Array`] is written as [`Binary`].
+//! Convert it into a [`bs58_fixed::Bs58String`] to write it as [`Text`] instead.
+//!
+//! It can be read from either a [`Binary`] column containing the raw `[u8; BUF_LEN]`
+//! or a [`Text`] column containing the base58 string.
+
+use bs58_fixed::{Bs58Str, Bs58StrDecodeErr};
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    serialize::{self, Output, ToSql},
+    sql_types::{Binary, Text},
+};
+
+use crate::Bs58Array;
+
+/// Only exists to derive `AsExpression<Binary>` and `FromSqlRow` for [`Bs58Array`].
+///
+/// Cannot derive on the struct definition itself because
+/// this module shadows the `diesel` crate at the crate root.
+#[allow(dead_code)]
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive, sql_type = Binary)]
+struct Bs58ArrayProxy<const BUF_LEN: usize, const MAX_STR_LEN: usize>(
+    Bs58Array<BUF_LEN, MAX_STR_LEN>,
+);
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize, DB: Backend> ToSql<Binary, DB>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+where
+    [u8]: ToSql<Binary, DB>,
+{
+    #[inline]
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.0.as_slice().to_sql(out)
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize, DB: Backend> FromSql<Binary, DB>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+where
+    Vec<u8>: FromSql<Binary, DB>,
+{
+    #[inline]
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let b = Vec::from_sql(bytes)?;
+        let buf = b.try_into().map_err(|_| Bs58StrDecodeErr::NotOfBufLen)?;
+        Ok(Self(buf))
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize, DB: Backend> FromSql<Text, DB>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+where
+    String: FromSql<Text, DB>,
+{
+    #[inline]
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let s = String::from_sql(bytes)?;
+        let mut res = Self::ZERO;
+        Bs58Str::<MAX_STR_LEN>::decode_from_onto(&s, &mut res.0)?;
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bs58_fixed::Bs58String;
+
+    use super::*;
+
+    crate::sql_tests::test_suite! {
+        diesel,
+        Bs58Array<BUF_LEN, MAX_STR_LEN>,
+        new: |buf| Bs58Array::new(buf),
+        text: |a| Bs58String::from(*a),
+        blob: |a| *a,
+    }
+}
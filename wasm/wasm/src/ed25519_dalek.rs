@@ -0,0 +1,112 @@
+//! [`ed25519_dalek`](https://docs.rs/ed25519-dalek) interop.
+//!
+//! Decoding a [`Bs58Ed25519Pk`] only checks that it is 32 bytes long, so it may be
+//! off-curve e.g. a program-derived address. Use [`Bs58Ed25519Pk::is_on_curve`]
+//! or convert it to a [`VerifyingKey`] to check curve membership.
+
+use ed25519_dalek::{Signature, SignatureError, VerifyingKey};
+
+use crate::Bs58Array;
+
+/// An ed25519 public key, which may or may not be on the curve
+pub type Bs58Ed25519Pk = Bs58Array<32, 44>;
+
+/// An ed25519 signature
+pub type Bs58Ed25519Sig = Bs58Array<64, 88>;
+
+/// ed25519-dalek
+impl Bs58Array<32, 44> {
+    /// Returns `true` if `self` is a valid compressed ed25519 point,
+    /// i.e. it can be converted to a [`VerifyingKey`]
+    #[inline]
+    pub fn is_on_curve(&self) -> bool {
+        self.to_verifying_key().is_ok()
+    }
+
+    /// Errors if `self` is not on the curve
+    #[inline]
+    pub fn to_verifying_key(&self) -> Result<VerifyingKey, SignatureError> {
+        VerifyingKey::from_bytes(&self.0)
+    }
+}
+
+impl From<&VerifyingKey> for Bs58Array<32, 44> {
+    #[inline]
+    fn from(value: &VerifyingKey) -> Self {
+        Self(value.to_bytes())
+    }
+}
+
+impl From<VerifyingKey> for Bs58Array<32, 44> {
+    #[inline]
+    fn from(value: VerifyingKey) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl TryFrom<Bs58Array<32, 44>> for VerifyingKey {
+    type Error = SignatureError;
+
+    /// Errors if `value` is not on the curve
+    #[inline]
+    fn try_from(value: Bs58Array<32, 44>) -> Result<Self, Self::Error> {
+        value.to_verifying_key()
+    }
+}
+
+impl From<&Signature> for Bs58Array<64, 88> {
+    #[inline]
+    fn from(value: &Signature) -> Self {
+        Self(value.to_bytes())
+    }
+}
+
+impl From<Signature> for Bs58Array<64, 88> {
+    #[inline]
+    fn from(value: Signature) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<Bs58Array<64, 88>> for Signature {
+    #[inline]
+    fn from(value: Bs58Array<64, 88>) -> Self {
+        Self::from_bytes(&value.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer, SigningKey, Verifier};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(secret: [u8; 32], msg: Vec<u8>) {
+            let signing_key = SigningKey::from_bytes(&secret);
+            let vk = signing_key.verifying_key();
+            let sig = signing_key.sign(&msg);
+
+            let pk = Bs58Ed25519Pk::from(vk);
+            prop_assert!(pk.is_on_curve());
+            prop_assert_eq!(pk.to_string(), bs58::encode(vk.as_bytes()).into_string());
+            let sig_arr = Bs58Ed25519Sig::from(sig);
+            prop_assert_eq!(sig_arr.to_string(), bs58::encode(sig.to_bytes()).into_string());
+
+            let vk = VerifyingKey::try_from(pk).unwrap();
+            prop_assert!(vk.verify(&msg, &Signature::from(sig_arr)).is_ok());
+        }
+    }
+
+    #[test]
+    fn off_curve() {
+        // y = 2 is not the y-coordinate of any point on the curve
+        let mut off_curve = [0u8; 32];
+        off_curve[0] = 2;
+        let pk = Bs58Ed25519Pk::new(off_curve);
+        assert!(!pk.is_on_curve());
+        assert!(VerifyingKey::try_from(pk).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+//! Non-throwing validation of js values as [`Bs58Array`]s.
+//!
+//! Use [`crate::declare_bs58_array_guards`] to export these to js for a declared type alias.
+
+use core::{error::Error, fmt::Display};
+
+use bs58_fixed::Bs58StrDecodeErr;
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::Bs58Array;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_APPEND_CONTENT: &'static str =
+    "export type TryParseResult<T> = { ok: true; value: T } | { ok: false; error: string };";
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Bs58ArrayFromJsErr {
+    NotString,
+    Bs58(Bs58StrDecodeErr),
+}
+
+impl Display for Bs58ArrayFromJsErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotString => f.write_str("not a string"),
+            Self::Bs58(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for Bs58ArrayFromJsErr {}
+
+/// Guards
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Bs58Array<BUF_LEN, MAX_STR_LEN> {
+    /// Decodes `x` if it is a string that is a valid base58 encoding of a `[u8; BUF_LEN]`
+    #[inline]
+    pub fn from_js_value(x: &JsValue) -> Result<Self, Bs58ArrayFromJsErr> {
+        let s = x.as_string().ok_or(Bs58ArrayFromJsErr::NotString)?;
+        s.parse().map_err(Bs58ArrayFromJsErr::Bs58)
+    }
+
+    /// Returns `{ ok: true, value: x }` if `x` is valid (see [`Self::from_js_value`]),
+    /// `{ ok: false, error }` otherwise
+    #[inline]
+    pub fn try_parse_js(x: &JsValue) -> JsValue {
+        let res = Object::new();
+        let (ok, k, v) = match Self::from_js_value(x) {
+            Ok(_) => (JsValue::TRUE, "value", x.clone()),
+            Err(e) => (JsValue::FALSE, "error", e.to_string().into()),
+        };
+        // setting fields on a newly created plain object never fails
+        Reflect::set(&res, &"ok".into(), &ok).unwrap_throw();
+        Reflect::set(&res, &k.into(), &v).unwrap_throw();
+        res.into()
+    }
+}
+
+/// Exports, for a [`Bs58Array`] type alias declared with `#[tsify_next::declare]`:
+/// - a ts type guard `$is(x: unknown): x is $Alias`
+/// - `$try_parse(x: unknown): TryParseResult<$Alias>`, which never throws
+///
+/// # Example
+///
+/// ```ignore
+/// #[tsify_next::declare]
+/// pub type Bs58Pk = Bs58Array<32, 44>;
+///
+/// bs58_fixed_wasm::declare_bs58_array_guards!(Bs58Pk, isBs58Pk, tryParseBs58Pk);
+/// ```
+#[macro_export]
+#[rustfmt::skip] // rustfmt keeps re-indenting the `$crate` attrs
+macro_rules! declare_bs58_array_guards {
+    ($Alias:ident, $is:ident, $try_parse:ident $(,)?) => {
+        #[allow(non_snake_case)]
+        #[$crate::__wasm_bindgen::prelude::wasm_bindgen(
+            skip_typescript,
+            wasm_bindgen = $crate::__wasm_bindgen
+        )]
+        pub fn $is(x: &$crate::__wasm_bindgen::JsValue) -> bool {
+            <$Alias>::from_js_value(x).is_ok()
+        }
+
+        #[allow(non_snake_case)]
+        #[$crate::__wasm_bindgen::prelude::wasm_bindgen(
+            skip_typescript,
+            wasm_bindgen = $crate::__wasm_bindgen
+        )]
+        pub fn $try_parse(x: &$crate::__wasm_bindgen::JsValue) -> $crate::__wasm_bindgen::JsValue {
+            <$Alias>::try_parse_js(x)
+        }
+
+        const _: () = {
+            #[$crate::__wasm_bindgen::prelude::wasm_bindgen(
+                typescript_custom_section,
+                wasm_bindgen = $crate::__wasm_bindgen
+            )]
+            const TS_APPEND_CONTENT: &'static str = concat!(
+                "export function ",
+                stringify!($is),
+                "(x: unknown): x is ",
+                stringify!($Alias),
+                ";\nexport function ",
+                stringify!($try_parse),
+                "(x: unknown): TryParseResult<",
+                stringify!($Alias),
+                ">;",
+            );
+        };
+    };
+}
@@ -0,0 +1,41 @@
+use bs58_fixed::{max_str_len, Len};
+
+use crate::Bs58Array;
+
+/// Relates a byte buffer length to the [`Bs58Array`] type for it.
+///
+/// Implemented by [`Len<BUF_LEN>`] for the same sizes as [`bs58_fixed::Bs58Len`]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a predefined bs58-fixed size",
+    label = "no `Bs58ArrayLen` impl for this size",
+    note = "for sizes that are not predefined, use `Bs58Array<BUF_LEN, MAX_STR_LEN>` directly with `MAX_STR_LEN = bs58_fixed::max_str_len(BUF_LEN)`"
+)]
+pub trait Bs58ArrayLen {
+    /// [`Bs58Array`] with `MAX_STR_LEN = max_str_len(BUF_LEN)`
+    type Array;
+}
+
+macro_rules! impl_bs58_array_len {
+    ($($BUF_LEN:expr),*) => {
+        $(
+            impl Bs58ArrayLen for Len<$BUF_LEN> {
+                type Array = Bs58Array<$BUF_LEN, { max_str_len($BUF_LEN) }>;
+            }
+        )*
+    };
+}
+
+bs58_fixed::__for_each_predefined_len!(impl_bs58_array_len);
+
+/// [`Bs58Array`] with `MAX_STR_LEN` derived from `BUF_LEN`.
+///
+/// Only available for the sizes `Len<BUF_LEN>` implements [`Bs58ArrayLen`] for.
+pub type Bs58FixedArray<const BUF_LEN: usize> = <Len<BUF_LEN> as Bs58ArrayLen>::Array;
+
+pub type Bs58Array16 = Bs58FixedArray<16>;
+
+pub type Bs58Array20 = Bs58FixedArray<20>;
+
+pub type Bs58Array32 = Bs58FixedArray<32>;
+
+pub type Bs58Array64 = Bs58FixedArray<64>;
@@ -2,15 +2,69 @@
 
 pub mod serde;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod class;
+#[cfg(feature = "codec")]
+mod codec;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "ed25519-dalek")]
+mod ed25519_dalek;
+mod guard;
+mod len;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+mod schema;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(all(test, any(feature = "diesel", feature = "rusqlite", feature = "sqlx")))]
+#[path = "../../../lib/src/sql_tests.rs"]
+mod sql_tests;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "utoipa")]
+mod utoipa;
 mod wasm_abi;
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
 
+use core::{array::TryFromSliceError, fmt::Display, str::FromStr};
+use std::borrow::Cow;
+
+use bs58_fixed::{Bs58Str, Bs58StrDecodeErr, Bs58String};
+
+#[cfg(feature = "ed25519-dalek")]
+pub use self::ed25519_dalek::*;
+pub use guard::*;
+pub use len::*;
 pub use wasm_abi::*;
 
+#[doc(hidden)]
+pub use wasm_bindgen as __wasm_bindgen;
+
 // impl notes:
 // would really only like BUF_LEN const-generic here, but limits of const-generics
 // interactions with traits (serde) mean we have to make MAX_STR_LEN part of the type
 // instead of computing it as an associated const or smth.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        ::zerocopy::FromBytes,
+        ::zerocopy::IntoBytes,
+        ::zerocopy::KnownLayout,
+        ::zerocopy::Immutable,
+        ::zerocopy::Unaligned,
+    )
+)]
 #[repr(transparent)]
 pub struct Bs58Array<const BUF_LEN: usize, const MAX_STR_LEN: usize>(pub [u8; BUF_LEN]);
 
@@ -28,3 +82,121 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Default for Bs58Array<BUF_L
         Self::ZERO
     }
 }
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Display for Bs58Array<BUF_LEN, MAX_STR_LEN> {
+    /// Same as [`Bs58String`]'s [`Display`] impl
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Bs58String::<MAX_STR_LEN>::encode(&self.0).fmt(f)
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> From<Bs58Array<BUF_LEN, MAX_STR_LEN>>
+    for Bs58String<MAX_STR_LEN>
+{
+    #[inline]
+    fn from(value: Bs58Array<BUF_LEN, MAX_STR_LEN>) -> Self {
+        Self::encode(&value.0)
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> From<&Bs58String<MAX_STR_LEN>>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    #[inline]
+    fn from(value: &Bs58String<MAX_STR_LEN>) -> Self {
+        Self(value.decode())
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> TryFrom<&[u8]>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    type Error = TryFromSliceError;
+
+    /// Errors if `buf` is not of len `BUF_LEN`
+    #[inline]
+    fn try_from(buf: &[u8]) -> Result<Self, Self::Error> {
+        buf.try_into().map(Self)
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> FromStr for Bs58Array<BUF_LEN, MAX_STR_LEN> {
+    type Err = Bs58StrDecodeErr;
+
+    /// Errors if `s` is not a base58-encoded buffer of len `BUF_LEN`
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bs58Str::<MAX_STR_LEN>::decode_from(s).map(|(_, buf)| Self(buf))
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> TryFrom<&str>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    type Error = Bs58StrDecodeErr;
+
+    /// Same as [`Self::from_str`]
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> TryFrom<String>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    type Error = Bs58StrDecodeErr;
+
+    /// Same as [`Self::from_str`]
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> TryFrom<Cow<'_, str>>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    type Error = Bs58StrDecodeErr;
+
+    /// Same as [`Self::from_str`]
+    #[inline]
+    fn try_from(s: Cow<'_, str>) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn from_str_round_trip(buf: [u8; 32]) {
+            let a = Bs58Array::<32, 44>::new(buf);
+            let s = a.to_string();
+            prop_assert_eq!(s.parse::<Bs58Array<32, 44>>().unwrap(), a);
+            prop_assert_eq!(Bs58Array::<32, 44>::try_from(s.as_str()).unwrap(), a);
+            prop_assert_eq!(Bs58Array::<32, 44>::try_from(Cow::from(&s)).unwrap(), a);
+            prop_assert_eq!(Bs58Array::<32, 44>::try_from(s.clone()).unwrap(), a);
+            prop_assert_eq!(
+                s.parse::<Bs58Array<64, 88>>().unwrap_err(),
+                Bs58StrDecodeErr::NotOfBufLen
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert_eq!(
+            "0".parse::<Bs58Array<32, 44>>().unwrap_err(),
+            Bs58StrDecodeErr::Bs58(bs58::decode::Error::InvalidCharacter {
+                character: '0',
+                index: 0
+            })
+        );
+    }
+}
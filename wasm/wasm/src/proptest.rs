@@ -0,0 +1,42 @@
+//! [`Bs58Array`]s are generated with the same edge-case biases as
+//! [`bs58_fixed::proptest::bs58_string`]
+
+use bs58_fixed::proptest::bs58_string;
+use proptest::{arbitrary::Arbitrary, prelude::*};
+
+use crate::Bs58Array;
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Arbitrary for Bs58Array<BUF_LEN, MAX_STR_LEN> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    #[inline]
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        bs58_string::<MAX_STR_LEN>()
+            .prop_map(|s| Self(s.decode()))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::test_runner::TestRunner;
+
+    use super::*;
+
+    #[test]
+    fn bs58_array_32_edge_case_bias() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = any::<Bs58Array<32, 44>>();
+        let samples: Vec<_> = (0..1000)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().0)
+            .collect();
+
+        // all vanishingly unlikely for uniformly random bytes
+        assert!(samples.contains(&[0u8; 32]));
+        assert!(samples.contains(&[0xFFu8; 32]));
+        assert!(samples
+            .iter()
+            .any(|buf| buf[..4] == [0u8; 4] && *buf != [0u8; 32]));
+    }
+}
@@ -0,0 +1,51 @@
+//! [`Bs58Array`] is written as a BLOB of its raw `[u8; BUF_LEN]`.
+//! Convert it into a [`bs58_fixed::Bs58String`] to write it as a TEXT base58 string instead.
+//!
+//! It can be read from either a BLOB column containing the raw bytes
+//! or a TEXT column containing the base58 string.
+
+use bs58_fixed::Bs58Str;
+use rusqlite::{
+    types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef},
+    Result, ToSql,
+};
+
+use crate::Bs58Array;
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> ToSql for Bs58Array<BUF_LEN, MAX_STR_LEN> {
+    #[inline]
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Blob(&self.0)))
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> FromSql for Bs58Array<BUF_LEN, MAX_STR_LEN> {
+    #[inline]
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(_) => {
+                let mut res = Self::ZERO;
+                Bs58Str::<MAX_STR_LEN>::decode_from_onto(value.as_str()?, &mut res.0)
+                    .map_err(FromSqlError::other)?;
+                Ok(res)
+            }
+            ValueRef::Blob(_) => <[u8; BUF_LEN]>::column_result(value).map(Self),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bs58_fixed::Bs58String;
+
+    use super::*;
+
+    crate::sql_tests::test_suite! {
+        rusqlite,
+        Bs58Array<BUF_LEN, MAX_STR_LEN>,
+        new: |buf| Bs58Array::new(buf),
+        text: |a| Bs58String::from(*a),
+        blob: |a| *a,
+    }
+}
@@ -0,0 +1,15 @@
+//! Common definitions for schema generation features
+
+use crate::Bs58Array;
+
+/// Regex matching strings consisting only of base58 alphabet chars
+pub(crate) const PATTERN: &str = "^[1-9A-HJ-NP-Za-km-z]*$";
+
+pub(crate) fn description<const BUF_LEN: usize>() -> String {
+    format!("base58-encoded {BUF_LEN}-byte buffer")
+}
+
+/// Encoding of `[1u8; BUF_LEN]`
+pub(crate) fn example<const BUF_LEN: usize, const MAX_STR_LEN: usize>() -> String {
+    Bs58Array::<BUF_LEN, MAX_STR_LEN>([1u8; BUF_LEN]).to_string()
+}
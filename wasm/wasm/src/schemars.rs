@@ -0,0 +1,68 @@
+//! [`Bs58Array`] is described as a JSON Schema string, which is how it's serialized,
+//! with the base58 alphabet pattern and exact length bounds
+
+use std::borrow::Cow;
+
+use bs58_fixed::{max_str_len, min_str_len};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{schema, Bs58Array};
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> JsonSchema
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    #[inline]
+    fn inline_schema() -> bool {
+        true
+    }
+
+    #[inline]
+    fn schema_name() -> Cow<'static, str> {
+        format!("Bs58Array{BUF_LEN}").into()
+    }
+
+    #[inline]
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": schema::description::<BUF_LEN>(),
+            "pattern": schema::PATTERN,
+            "minLength": min_str_len(BUF_LEN),
+            "maxLength": max_str_len(BUF_LEN),
+            "examples": [schema::example::<BUF_LEN, MAX_STR_LEN>()],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn bs58_array_32_schema() {
+        let schema = schemars::schema_for!(Bs58Array<32, 44>);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Bs58Array32",
+                "type": "string",
+                "description": "base58-encoded 32-byte buffer",
+                "pattern": "^[1-9A-HJ-NP-Za-km-z]*$",
+                "minLength": 32,
+                "maxLength": 44,
+                "examples": ["4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi"],
+            })
+        );
+    }
+
+    #[test]
+    fn bs58_array_64_schema() {
+        let schema = schemars::schema_for!(Bs58Array<64, 88>);
+        assert_eq!(schema.get("title").unwrap(), "Bs58Array64");
+        assert_eq!(schema.get("minLength").unwrap(), 64);
+        assert_eq!(schema.get("maxLength").unwrap(), 88);
+    }
+}
@@ -1,6 +1,13 @@
-//! This is a `#[serde(with = "bs58_fixed_wasm::serde")]` compatible module
+//! This is a `#[serde(with = "bs58_fixed_wasm::serde")]` compatible module.
+//! Use [`Codec`] for other [`bs58_fixed::FixedStrCodec`] representations e.g. hex.
+//!
+//! Since [`Bs58Array`] (de)serializes as a string, it can also be used as the key of
+//! `HashMap`s and `BTreeMap`s, which become `Record<Bs58Array, T>` in ts
+//! for the `json` feature, or with `#[tsify(hashmap_as_object)]` for the `js` feature
 
-use bs58_fixed::buf_len;
+use core::marker::PhantomData;
+
+use bs58_fixed::{Bs58String, FixedStrCodec};
 use serde::{de::Visitor, Deserializer, Serializer};
 
 use crate::Bs58Array;
@@ -12,28 +19,81 @@ pub fn serialize<S: Serializer, const BUF_LEN: usize, const MAX_STR_LEN: usize>(
     val: &Bs58Array<BUF_LEN, MAX_STR_LEN>,
     ser: S,
 ) -> Result<S::Ok, S::Error> {
-    const {
-        assert!(buf_len(MAX_STR_LEN) == BUF_LEN);
-    }
-
-    let bs58_string = bs58_fixed::Bs58String::<MAX_STR_LEN>::encode(&val.0);
-    ser.serialize_str(bs58_string.as_str())
+    Codec::<Bs58String<MAX_STR_LEN>>::serialize(val, ser)
 }
 
 // De
 
+#[inline]
+pub fn deserialize<'de, D: Deserializer<'de>, const BUF_LEN: usize, const MAX_STR_LEN: usize>(
+    de: D,
+) -> Result<Bs58Array<BUF_LEN, MAX_STR_LEN>, D::Error> {
+    Codec::<Bs58String<MAX_STR_LEN>>::deserialize(de)
+}
+
+/// `#[serde(with = ...)]` compatible (de)serialization of [`Bs58Array`]s
+/// as strings of the [`FixedStrCodec`] `C` instead of base58.
+///
+/// # Example
+///
+/// ```
+/// use bs58_fixed::{Base64String, HexString};
+/// use bs58_fixed_wasm::Bs58Array;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Keys {
+///     bs58: Bs58Array<32, 44>,
+///
+///     #[serde(with = "bs58_fixed_wasm::serde::Codec::<HexString<64>>")]
+///     hex: Bs58Array<32, 44>,
+///
+///     #[serde(with = "bs58_fixed_wasm::serde::Codec::<Base64String<44>>")]
+///     base64: Bs58Array<32, 44>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Codec<C>(PhantomData<C>);
+
+impl<C> Codec<C> {
+    #[inline]
+    pub fn serialize<S: Serializer, const BUF_LEN: usize, const MAX_STR_LEN: usize>(
+        val: &Bs58Array<BUF_LEN, MAX_STR_LEN>,
+        ser: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        C: FixedStrCodec<BUF_LEN>,
+    {
+        ser.serialize_str(&C::encode(&val.0))
+    }
+
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>, const BUF_LEN: usize, const MAX_STR_LEN: usize>(
+        de: D,
+    ) -> Result<Bs58Array<BUF_LEN, MAX_STR_LEN>, D::Error>
+    where
+        C: FixedStrCodec<BUF_LEN>,
+    {
+        de.deserialize_str(CodecVisitor::<C, BUF_LEN, MAX_STR_LEN>(PhantomData))
+    }
+}
+
 // impl visitor pattern so that it works for both String and &str
 
-struct Bs58ArrayVisitor<const BUF_LEN: usize, const MAX_STR_LEN: usize>;
+struct CodecVisitor<C, const BUF_LEN: usize, const MAX_STR_LEN: usize>(PhantomData<C>);
 
-impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Visitor<'_>
-    for Bs58ArrayVisitor<BUF_LEN, MAX_STR_LEN>
+impl<C: FixedStrCodec<BUF_LEN>, const BUF_LEN: usize, const MAX_STR_LEN: usize> Visitor<'_>
+    for CodecVisitor<C, BUF_LEN, MAX_STR_LEN>
 {
     type Value = Bs58Array<BUF_LEN, MAX_STR_LEN>;
 
     #[inline]
     fn expecting(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(fmt, "base58 encoded string of byte buffer of len {BUF_LEN}")
+        write!(
+            fmt,
+            "{} encoded string of byte buffer of len {BUF_LEN}",
+            C::NAME
+        )
     }
 
     #[inline]
@@ -41,23 +101,11 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Visitor<'_>
     where
         E: serde::de::Error,
     {
-        const {
-            assert!(buf_len(MAX_STR_LEN) == BUF_LEN);
-        }
-
-        let (_, buf) =
-            bs58_fixed::Bs58Str::<MAX_STR_LEN>::decode_from(v).map_err(serde::de::Error::custom)?;
+        let (_, buf) = C::decode_from(v).map_err(serde::de::Error::custom)?;
         Ok(Bs58Array(buf))
     }
 }
 
-#[inline]
-pub fn deserialize<'de, D: Deserializer<'de>, const BUF_LEN: usize, const MAX_STR_LEN: usize>(
-    de: D,
-) -> Result<Bs58Array<BUF_LEN, MAX_STR_LEN>, D::Error> {
-    de.deserialize_str(Bs58ArrayVisitor)
-}
-
 mod impls {
     use serde::{Deserialize, Serialize};
 
@@ -85,3 +133,98 @@ mod impls {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    macro_rules! test_suite {
+        ($BUF_LEN:expr, $MAX_STR_LEN:expr, $test_name:ident) => {
+            proptest! {
+                #[test]
+                fn $test_name(bufs: HashMap<[u8; $BUF_LEN], u32>) {
+                    type A = Bs58Array<$BUF_LEN, $MAX_STR_LEN>;
+
+                    let hm: HashMap<A, u32> = bufs.iter().map(|(k, v)| (A::new(*k), *v)).collect();
+                    let bm: BTreeMap<A, u32> = hm.iter().map(|(k, v)| (*k, *v)).collect();
+
+                    let json = serde_json::to_value(&hm).unwrap();
+                    let obj = json.as_object().unwrap();
+                    prop_assert_eq!(obj.len(), hm.len());
+                    for (k, v) in hm.iter() {
+                        prop_assert_eq!(&obj[&k.to_string()], v);
+                    }
+                    prop_assert_eq!(&serde_json::to_value(&bm).unwrap(), &json);
+
+                    prop_assert_eq!(serde_json::from_value::<HashMap<A, u32>>(json.clone()).unwrap(), hm);
+                    prop_assert_eq!(serde_json::from_value::<BTreeMap<A, u32>>(json).unwrap(), bm);
+                }
+            }
+        };
+    }
+
+    test_suite!(0, 1, b_0_1_test);
+    test_suite!(1, 2, b_1_2_test);
+    test_suite!(16, 22, b_16_22_test);
+    test_suite!(20, 28, b_20_28_test);
+    test_suite!(32, 44, b_32_44_test);
+    test_suite!(64, 88, b_64_88_test);
+
+    #[derive(Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Keys {
+        bs58: Bs58Array<32, 44>,
+
+        #[serde(with = "Codec::<bs58_fixed::HexString<64>>")]
+        hex: Bs58Array<32, 44>,
+
+        #[serde(with = "Codec::<bs58_fixed::Base64String<44>>")]
+        base64: Bs58Array<32, 44>,
+    }
+
+    proptest! {
+        #[test]
+        fn codec_round_trip(buf: [u8; 32]) {
+            let a = Bs58Array::<32, 44>::new(buf);
+            let keys = Keys { bs58: a, hex: a, base64: a };
+
+            let json = serde_json::to_value(&keys).unwrap();
+            prop_assert_eq!(&json["bs58"], &bs58::encode(buf).into_string());
+            prop_assert_eq!(
+                &json["hex"],
+                &buf.iter().map(|b| format!("{b:02x}")).collect::<String>()
+            );
+            prop_assert_eq!(serde_json::from_value::<Keys>(json).unwrap(), keys);
+        }
+    }
+
+    #[test]
+    fn codec_invalid() {
+        let hex = "01".repeat(32);
+        let base64 = "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=";
+        let bs58 = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+        let valid = format!(r#"{{"bs58":"{bs58}","hex":"{hex}","base64":"{base64}"}}"#);
+        assert!(serde_json::from_str::<Keys>(&valid).is_ok());
+
+        // each field only accepts its own codec
+        for invalid in [
+            format!(r#"{{"bs58":"{bs58}","hex":"{bs58}","base64":"{base64}"}}"#),
+            format!(r#"{{"bs58":"{bs58}","hex":"{hex}","base64":"{hex}"}}"#),
+            format!(r#"{{"bs58":"{hex}","hex":"{hex}","base64":"{base64}"}}"#),
+            format!(
+                r#"{{"bs58":"{bs58}","hex":"{}","base64":"{base64}"}}"#,
+                &hex[2..]
+            ),
+        ] {
+            assert!(serde_json::from_str::<Keys>(&invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(serde_json::from_str::<HashMap<Bs58Array<32, 44>, u32>>(r#"{"0": 1}"#).is_err());
+    }
+}
@@ -0,0 +1,84 @@
+//! [`Bs58Array`] is encoded as a BLOB/BYTEA of its raw `[u8; BUF_LEN]`.
+//! Convert it into a [`bs58_fixed::Bs58String`] to encode it as a TEXT base58 string instead.
+//!
+//! It can be decoded from either a BLOB/BYTEA column containing the raw bytes
+//! or a TEXT column containing the base58 string.
+
+use bs58_fixed::{Bs58Str, Bs58StrDecodeErr};
+use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type, ValueRef};
+
+use crate::Bs58Array;
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize, DB: Database> Type<DB>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+where
+    str: Type<DB>,
+    [u8]: Type<DB>,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        <[u8] as Type<DB>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <[u8] as Type<DB>>::compatible(ty) || <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, const BUF_LEN: usize, const MAX_STR_LEN: usize, DB: Database> Encode<'q, DB>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+where
+    for<'a> &'a [u8]: Encode<'q, DB>,
+{
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.0.as_slice().encode_by_ref(buf)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.0.as_slice().size_hint()
+    }
+}
+
+impl<'r, const BUF_LEN: usize, const MAX_STR_LEN: usize, DB: Database> Decode<'r, DB>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+where
+    str: Type<DB>,
+    &'r str: Decode<'r, DB>,
+    &'r [u8]: Decode<'r, DB>,
+{
+    #[inline]
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let mut res = Self::ZERO;
+        // some backends (e.g. sqlite) allow decoding TEXT values as bytes,
+        // so must check for TEXT first
+        if <str as Type<DB>>::compatible(&value.type_info()) {
+            let s = <&str as Decode<DB>>::decode(value)?;
+            Bs58Str::<MAX_STR_LEN>::decode_from_onto(s, &mut res.0)?;
+        } else {
+            let b = <&[u8] as Decode<DB>>::decode(value)?;
+            if b.len() != BUF_LEN {
+                return Err(Bs58StrDecodeErr::NotOfBufLen.into());
+            }
+            res.0.copy_from_slice(b);
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bs58_fixed::Bs58String;
+
+    use super::*;
+
+    crate::sql_tests::test_suite! {
+        sqlx,
+        Bs58Array<BUF_LEN, MAX_STR_LEN>,
+        new: |buf| Bs58Array::new(buf),
+        text: |a| Bs58String::from(*a),
+        blob: |a| *a,
+    }
+}
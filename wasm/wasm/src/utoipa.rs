@@ -0,0 +1,58 @@
+//! [`Bs58Array`] is described as an OpenAPI string schema, which is how it's serialized,
+//! with the base58 alphabet pattern and exact length bounds
+
+use std::borrow::Cow;
+
+use bs58_fixed::{max_str_len, min_str_len};
+use utoipa::{
+    openapi::{schema::Type, ObjectBuilder, RefOr, Schema},
+    PartialSchema, ToSchema,
+};
+
+use crate::{schema, Bs58Array};
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> PartialSchema
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    #[inline]
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some(schema::description::<BUF_LEN>()))
+            .pattern(Some(schema::PATTERN))
+            .min_length(Some(min_str_len(BUF_LEN)))
+            .max_length(Some(max_str_len(BUF_LEN)))
+            .examples([schema::example::<BUF_LEN, MAX_STR_LEN>()])
+            .into()
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> ToSchema for Bs58Array<BUF_LEN, MAX_STR_LEN> {
+    #[inline]
+    fn name() -> Cow<'static, str> {
+        format!("Bs58Array{BUF_LEN}").into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn bs58_array_32_schema() {
+        assert_eq!(Bs58Array::<32, 44>::name(), "Bs58Array32");
+        assert_eq!(
+            serde_json::to_value(Bs58Array::<32, 44>::schema()).unwrap(),
+            json!({
+                "type": "string",
+                "description": "base58-encoded 32-byte buffer",
+                "pattern": "^[1-9A-HJ-NP-Za-km-z]*$",
+                "minLength": 32,
+                "maxLength": 44,
+                "examples": ["4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi"],
+            })
+        );
+    }
+}
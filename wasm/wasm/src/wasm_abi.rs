@@ -1,16 +1,17 @@
 use core::ops::Deref;
 
+use bs58_fixed::Bs58String;
 use tsify_next::Tsify;
 use wasm_bindgen::{
     convert::{
         FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi, RefFromWasmAbi,
-        VectorFromWasmAbi, VectorIntoWasmAbi,
+        TryFromJsValue, VectorFromWasmAbi, VectorIntoWasmAbi,
     },
     describe::{WasmDescribe, WasmDescribeVector},
     prelude::*,
 };
 
-use crate::Bs58Array;
+use crate::{Bs58Array, Bs58ArrayFromJsErr};
 
 #[wasm_bindgen]
 unsafe extern "C" {
@@ -28,8 +29,20 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Tsify for Bs58Array<BUF_LEN
     const DECL: &'static str = DECL;
 }
 
-// Below snippets are copied from expansion of #[derive(Tsify)] macro
-// with #[tsify(into_wasm_abi, from_wasm_abi)]
+// Below snippets are adapted from expansion of #[derive(Tsify)] macro
+// with #[tsify(into_wasm_abi, from_wasm_abi)], but convert to and from js strings
+// directly instead of going through serde, so they work the same regardless
+// of the `js`/`json` feature
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> Bs58Array<BUF_LEN, MAX_STR_LEN> {
+    #[inline]
+    fn from_js_value_or_throw(js: &JsValue) -> Self {
+        match Self::from_js_value(js) {
+            Ok(res) => res,
+            Err(err) => wasm_bindgen::throw_str(err.to_string().as_ref()),
+        }
+    }
+}
 
 impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> WasmDescribe
     for Bs58Array<BUF_LEN, MAX_STR_LEN>
@@ -56,27 +69,7 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> IntoWasmAbi
 
     #[inline]
     fn into_abi(self) -> Self::Abi {
-        // wasm_bindgen doesn't forward the error message from the `into_js` result.
-        // https://github.com/rustwasm/wasm-bindgen/issues/2732
-        // Until that issue is fixed, we don't directly use `unwrap_throw()` and instead build our
-        // own error message.
-        // Convert to `self.into_js().unwrap_throw().into_abi()` when fixed.
-        match self.into_js() {
-            Ok(js) => js.into_abi(),
-            Err(err) => {
-                let loc = core::panic::Location::caller();
-                // In theory, `wasm_bindgen::throw_str(&msg)` should work,
-                // but the error emitted by `wasm_bindgen::throw_str` cannot be picked up by
-                // `#[should_panic(expect = ...)]` in tests, so we use a regular panic.
-                panic!(
-                    "(Converting type failed) {} ({}:{}:{})",
-                    err,
-                    loc.file(),
-                    loc.line(),
-                    loc.column()
-                );
-            }
-        }
+        JsValue::from(self).into_abi()
     }
 }
 
@@ -94,27 +87,7 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> From<Bs58Array<BUF_LEN, MAX
 {
     #[inline]
     fn from(value: Bs58Array<BUF_LEN, MAX_STR_LEN>) -> Self {
-        // wasm_bindgen doesn't forward the error message from the `into_js` result.
-        // https://github.com/rustwasm/wasm-bindgen/issues/2732
-        // Until that issue is fixed, we don't directly use `unwrap_throw()` and instead build our
-        // own error message.
-        // Convert to `value.into_js().unwrap_throw().into()` when fixed.
-        match value.into_js() {
-            Ok(js) => js.into(),
-            Err(err) => {
-                let loc = core::panic::Location::caller();
-                // In theory, `wasm_bindgen::throw_str(&msg)` should work,
-                // but the error emitted by `wasm_bindgen::throw_str` cannot be picked up by
-                // `#[should_panic(expect = ...)]` in tests, so we use a regular panic.
-                panic!(
-                    "(Converting type failed) {} ({}:{}:{})",
-                    err,
-                    loc.file(),
-                    loc.line(),
-                    loc.column()
-                );
-            }
-        }
+        JsValue::from_str(Bs58String::<MAX_STR_LEN>::encode(&value.0).as_str())
     }
 }
 
@@ -123,35 +96,14 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> VectorIntoWasmAbi
 {
     type Abi = <JsType as VectorIntoWasmAbi>::Abi;
 
+    /// Converts each element with [`JsValue::from`].
+    ///
+    /// wasm-bindgen's `Vec<T>` and `Option<Box<[T]>>` impls require the ABI
+    /// to be a [`wasm_bindgen::convert::WasmSlice`] of js value handles,
+    /// so a single js array value can't be returned instead
     #[inline]
     fn vector_into_abi(vector: Box<[Self]>) -> Self::Abi {
-        // TODO: intermediate vec allocation looks unnecessary here
-        // but idk how to remove it
-        let values = vector
-            .iter()
-            // wasm_bindgen doesn't forward the error message from the `into_js` result.
-            // https://github.com/rustwasm/wasm-bindgen/issues/2732
-            // Until that issue is fixed, we don't directly use `unwrap_throw()` and instead build our
-            // own error message.
-            .map(|value| match value.into_js() {
-                Ok(js) => js.into(),
-                Err(err) => {
-                    let loc = core::panic::Location::caller();
-                    // In theory, `wasm_bindgen::throw_str(&msg)` should work,
-                    // but the error emitted by `wasm_bindgen::throw_str` cannot be picked up by
-                    // `#[should_panic(expect = ...)]` in tests, so we use a regular panic.
-                    panic!(
-                        "(Converting type failed) {} ({}:{}:{})",
-                        err,
-                        loc.file(),
-                        loc.line(),
-                        loc.column()
-                    );
-                }
-            })
-            .collect();
-
-        JsValue::vector_into_abi(values)
+        JsValue::vector_into_abi(vector.iter().copied().map(JsValue::from).collect())
     }
 }
 
@@ -162,11 +114,7 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> FromWasmAbi
 
     #[inline]
     unsafe fn from_abi(js: Self::Abi) -> Self {
-        let result = Self::from_js(JsType::from_abi(js));
-        if let Err(err) = result {
-            wasm_bindgen::throw_str(err.to_string().as_ref());
-        }
-        result.unwrap_throw()
+        Self::from_js_value_or_throw(&JsType::from_abi(js))
     }
 }
 
@@ -179,6 +127,28 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> OptionFromWasmAbi
     }
 }
 
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> TryFromJsValue
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    /// Same as [`Self::from_js_value`], but discarding the error
+    #[inline]
+    fn try_from_js_value_ref(value: &JsValue) -> Option<Self> {
+        Self::from_js_value(value).ok()
+    }
+}
+
+impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> TryFrom<&JsValue>
+    for Bs58Array<BUF_LEN, MAX_STR_LEN>
+{
+    type Error = Bs58ArrayFromJsErr;
+
+    /// Same as [`Self::from_js_value`]
+    #[inline]
+    fn try_from(value: &JsValue) -> Result<Self, Self::Error> {
+        Self::from_js_value(value)
+    }
+}
+
 // Self does not impl Deref<Self> but wasm_bindgen traits
 // need RefFromWasmAbi::Anchor to impl Deref<Self>, so just make this stupid wrapper
 #[repr(transparent)]
@@ -202,11 +172,7 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> RefFromWasmAbi
 
     #[inline]
     unsafe fn ref_from_abi(js: Self::Abi) -> Self::Anchor {
-        let result = Self::from_js(&*JsType::ref_from_abi(js));
-        if let Err(err) = result {
-            wasm_bindgen::throw_str(err.to_string().as_ref());
-        }
-        Bs58ArrayAnchor(result.unwrap_throw())
+        Bs58ArrayAnchor(Self::from_js_value_or_throw(&JsType::ref_from_abi(js)))
     }
 }
 
@@ -215,19 +181,13 @@ impl<const BUF_LEN: usize, const MAX_STR_LEN: usize> VectorFromWasmAbi
 {
     type Abi = <JsType as VectorFromWasmAbi>::Abi;
 
+    /// Converts each element with [`Self::from_js_value`],
+    /// so elements are checked the same way single values are
     #[inline]
     unsafe fn vector_from_abi(js: Self::Abi) -> Box<[Self]> {
-        // TODO: intermediate vec allocation looks unnecessary here
-        // but idk how to remove it
         JsValue::vector_from_abi(js)
             .iter()
-            .map(|value| {
-                let result = Self::from_js(value);
-                if let Err(err) = result {
-                    wasm_bindgen::throw_str(err.to_string().as_ref());
-                }
-                result.unwrap_throw()
-            })
+            .map(Self::from_js_value_or_throw)
             .collect()
     }
 }
@@ -0,0 +1,48 @@
+//! zerocopy traits are derived on [`Bs58Array`] directly since they can only be derived.
+//!
+//! This module only contains tests.
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+    use zerocopy::{FromBytes, FromZeros, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+    use crate::Bs58Array;
+
+    type Pk = Bs58Array<32, 44>;
+
+    #[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+    #[repr(C)]
+    struct Account {
+        owner: Pk,
+        mint: Pk,
+    }
+
+    proptest! {
+        #[test]
+        fn cast_round_trip(bufs in vec(any::<[u8; 32]>(), 0..=8)) {
+            let data = bufs.concat();
+            let pks = <[Pk]>::ref_from_bytes(&data).unwrap();
+            prop_assert_eq!(pks, bufs.iter().copied().map(Pk::new).collect::<Vec<_>>());
+            prop_assert_eq!(pks.as_bytes(), data.as_slice());
+        }
+
+        #[test]
+        fn struct_round_trip(data: [u8; 64]) {
+            let acc = Account::ref_from_bytes(&data).unwrap();
+            prop_assert_eq!(acc.owner.0.as_slice(), &data[..32]);
+            prop_assert_eq!(acc.mint.0.as_slice(), &data[32..]);
+            prop_assert_eq!(acc.as_bytes(), data.as_slice());
+        }
+
+        #[test]
+        fn wrong_len_rejected(data in vec(any::<u8>(), 1..=31)) {
+            prop_assert!(Pk::ref_from_bytes(&data).is_err());
+        }
+    }
+
+    #[test]
+    fn zeroed() {
+        assert_eq!(Pk::new_zeroed(), Pk::ZERO);
+    }
+}